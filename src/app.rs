@@ -1,7 +1,9 @@
 use super::*;
 
+const CALLBACK_BASE_ID: i32 = 1000;
+
 /// An id for a steam app/game
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AppId(pub u32);
 
@@ -12,6 +14,19 @@ pub struct Apps<Manager> {
 }
 
 impl <Manager> Apps<Manager> {
+    /// Returns the raw `ISteamApps` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamApps {
+        self.apps
+    }
+
 
     /// Returns whether the user currently has the app with the given
     /// ID currently installed.
@@ -132,6 +147,31 @@ impl <Manager> Apps<Manager> {
         }
     }
 
+    /// Returns the command line the game was launched with through
+    /// a steam url, if any.
+    pub fn launch_command_line(&self) -> String {
+        unsafe {
+            let mut buffer = vec![0; 1024];
+            let len = sys::SteamAPI_ISteamApps_GetLaunchCommandLine(self.apps, buffer.as_mut_ptr(), buffer.len() as _);
+            if len <= 0 {
+                return String::new();
+            }
+            CStr::from_ptr(buffer.as_ptr()).to_string_lossy().into_owned()
+        }
+    }
+
+    /// Returns the rich presence connect string the game was launched
+    /// with, if it was started by joining a friend.
+    ///
+    /// This checks both the steam launch command line and the process
+    /// arguments for a `+connect <value>` pair. Joins while the game is
+    /// already running are sent as `GameRichPresenceJoinRequested` instead.
+    pub fn launch_connect_string(&self) -> Option<String> {
+        let command_line = self.launch_command_line();
+        connect_from_args(command_line.split_whitespace())
+            .or_else(|| connect_from_args(std::env::args_os().skip(1).filter_map(|v| v.into_string().ok())))
+    }
+
     /// Returns the current beta name if any.
     ///
     /// If the user isn't playing on a beta branch then this
@@ -147,4 +187,102 @@ impl <Manager> Apps<Manager> {
             }
         }
     }
+
+    /// Requests the legacy cd key for the given app.
+    ///
+    /// The key is returned in an `AppProofOfPurchaseKeyResponse`
+    /// callback. Only useful for older titles that were sold with
+    /// keys.
+    pub fn request_app_proof_of_purchase_key(&self, app_id: AppId) {
+        unsafe {
+            sys::SteamAPI_ISteamApps_RequestAppProofOfPurchaseKey(self.apps, app_id.0);
+        }
+    }
+
+    /// Requests the legacy cd keys for all apps the user owns.
+    ///
+    /// An `AppProofOfPurchaseKeyResponse` callback is fired for
+    /// each app.
+    pub fn request_all_proof_of_purchase_keys(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamApps_RequestAllProofOfPurchaseKeys(self.apps);
+        }
+    }
+}
+
+/// Finds the value following a `+connect` argument
+fn connect_from_args<I, S>(args: I) -> Option<String>
+    where I: IntoIterator<Item = S>,
+          S: AsRef<str>,
+{
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let arg = arg.as_ref();
+        if arg == "+connect" {
+            return args.next().map(|v| v.as_ref().to_owned());
+        } else if let Some(connect) = arg.strip_prefix("+connect=") {
+            return Some(connect.to_owned());
+        }
+    }
+    None
+}
+
+/// Called when a dlc has finished installing.
+///
+/// The dlc's content can be loaded straight away without
+/// restarting the game.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DlcInstalled {
+    /// The app id of the dlc that was installed
+    pub app_id: AppId,
+}
+
+unsafe impl Callback for DlcInstalled {
+    const ID: i32 = CALLBACK_BASE_ID + 5;
+    const SIZE: i32 = ::std::mem::size_of::<sys::DlcInstalled_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::DlcInstalled_t);
+        DlcInstalled {
+            app_id: AppId(val.m_nAppID),
+        }
+    }
+}
+
+/// Called with a key requested by `Apps::request_app_proof_of_purchase_key`
+/// or `Apps::request_all_proof_of_purchase_keys`
+#[derive(Clone, Debug)]
+pub struct AppProofOfPurchaseKeyResponse {
+    /// The app the key belongs to
+    pub app_id: AppId,
+    /// The key if the request succeeded
+    pub key: SResult<String>,
+}
+
+unsafe impl Callback for AppProofOfPurchaseKeyResponse {
+    const ID: i32 = CALLBACK_BASE_ID + 21;
+    const SIZE: i32 = ::std::mem::size_of::<sys::AppProofOfPurchaseKeyResponse_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::AppProofOfPurchaseKeyResponse_t);
+        AppProofOfPurchaseKeyResponse {
+            app_id: AppId(val.m_nAppID),
+            key: match val.m_eResult {
+                sys::EResult::k_EResultOK => {
+                    let key = CStr::from_ptr(val.m_rgchKey.as_ptr());
+                    Ok(key.to_string_lossy().into_owned())
+                },
+                err => Err(err.into()),
+            },
+        }
+    }
+}
+
+#[test]
+fn connect_args() {
+    assert_eq!(connect_from_args(&["game", "+connect", "10.0.0.1:27015"]), Some("10.0.0.1:27015".to_owned()));
+    assert_eq!(connect_from_args(&["+connect=lobby"]), Some("lobby".to_owned()));
+    assert_eq!(connect_from_args(&["-windowed", "+connect"]), None);
+    assert_eq!(connect_from_args(&["-windowed"]), None);
 }
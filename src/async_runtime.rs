@@ -0,0 +1,89 @@
+//! Runtime agnostic support for awaiting steam call results.
+//!
+//! Futures created here don't depend on any executor. They are woken
+//! from `SingleClient::run_callbacks` (or `tick`) when steam delivers
+//! their result, so the same code works under tokio, smol or a hand
+//! rolled loop as long as something keeps pumping callbacks.
+//!
+//! ```no_run
+//! # use steamworks::*;
+//! # async fn example(client: Client) {
+//! let (future, cb) = async_runtime::callback_future();
+//! client.user_stats().find_leaderboard("high_scores", cb);
+//! let leaderboard = future.await;
+//! # }
+//! ```
+//!
+//! Only available with the `async` feature.
+
+use super::*;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+struct Shared<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// A future that resolves once the callback returned alongside it
+/// by `callback_future` is called
+pub struct CallbackFuture<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl <T> Future for CallbackFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Creates a future along with a callback that completes it.
+///
+/// The callback can be passed to any method that reports its result
+/// through a callback, such as `UserStats::find_leaderboard`, turning
+/// it into something that can be awaited. Only the first value passed
+/// to the callback is used.
+pub fn callback_future<T>() -> (CallbackFuture<T>, impl FnMut(T) + Send + 'static)
+    where T: Send + 'static
+{
+    let shared = Arc::new(Mutex::new(Shared {
+        value: None,
+        waker: None,
+    }));
+    let future = CallbackFuture {
+        shared: shared.clone(),
+    };
+    let cb = move |value| {
+        let waker = {
+            let mut shared = shared.lock().unwrap();
+            if shared.value.is_none() {
+                shared.value = Some(value);
+            }
+            shared.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    };
+    (future, cb)
+}
+
+/// Pumps pending steam callbacks, waking any futures whose results
+/// have arrived.
+///
+/// This should be called regularly, for example from a timer on the
+/// runtime in use or once per frame.
+pub fn tick<M: Manager>(single: &SingleClient<M>) {
+    single.run_callbacks();
+}
@@ -18,6 +18,7 @@ pub unsafe trait Callback {
 pub struct CallbackHandle<Manager = ClientManager> {
     id: i32,
     inner: Weak<Inner<Manager>>,
+    callback: Weak<Mutex<dyn FnMut(*mut c_void) + Send + 'static>>,
 }
 unsafe impl <Manager> Send for CallbackHandle<Manager> {}
 
@@ -25,7 +26,12 @@ impl <Manager> Drop for CallbackHandle<Manager> {
     fn drop(&mut self) {
         if let Some(inner) = self.inner.upgrade() {
             let mut cb = inner.callbacks.lock().unwrap();
-            cb.callbacks.remove(&self.id);
+            if let Some(callbacks) = cb.callbacks.get_mut(&self.id) {
+                callbacks.retain(|v| !Weak::ptr_eq(&Arc::downgrade(v), &self.callback));
+                if callbacks.is_empty() {
+                    cb.callbacks.remove(&self.id);
+                }
+            }
         }
     }
 }
@@ -34,17 +40,20 @@ pub(crate) unsafe fn register_callback<C, F, Manager>(inner: &Arc<Inner<Manager>
     where C: Callback,
           F: FnMut(C) + Send + 'static
 {
-    {
-        let mut callbacks = inner.callbacks.lock().unwrap();
-        callbacks.callbacks.insert(C::ID, Box::new(move |param| {
-            let param = C::from_raw(param);
-            f(param)
-        }));
-    }
-    CallbackHandle {
+    let callback: SharedCallback = Arc::new(Mutex::new(move |param| {
+        let param = C::from_raw(param);
+        f(param)
+    }));
+    let handle = CallbackHandle {
         id: C::ID,
         inner: Arc::downgrade(&inner),
+        callback: Arc::downgrade(&callback),
+    };
+    {
+        let mut callbacks = inner.callbacks.lock().unwrap();
+        callbacks.callbacks.entry(C::ID).or_default().push(callback);
     }
+    handle
 }
 
 pub(crate) unsafe fn register_call_result<C, F, Manager>(inner: &Arc<Inner<Manager>>, api_call: sys::SteamAPICall_t, _callback_id: i32, mut f: F)
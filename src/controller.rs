@@ -0,0 +1,244 @@
+//! Compatibility wrapper for the legacy `ISteamController` interface.
+//!
+//! `ISteamController` has been superseded by steam input (see
+//! `Client::input`) but is still shipped by the sdk. This module maps
+//! the old entry points onto the same handle types used by `Input` so
+//! code written against the old interface can be moved over one call
+//! at a time.
+//!
+//! ```no_run
+//! # use steamworks::*;
+//! # let (client, single) = Client::init().unwrap();
+//! let controller = client.controller();
+//! controller.init();
+//! controller.run_frame();
+//! let jump = controller.digital_action_handle("jump");
+//! for handle in controller.connected_controllers() {
+//!     if controller.digital_action_data(handle, jump).state {
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! Only available with the `controller` feature.
+
+use super::*;
+
+/// A handle to a controller, identical to the steam input handle
+pub type ControllerHandle = InputHandle;
+
+/// Access to the legacy steam controller interface
+pub struct Controller<Manager> {
+    pub(crate) controller: *mut sys::ISteamController,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+/// A handle to a digital (button) action
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DigitalActionHandle(pub(crate) sys::ControllerDigitalActionHandle_t);
+
+impl DigitalActionHandle {
+    /// Creates a `DigitalActionHandle` from a raw 64 bit value.
+    pub fn from_raw(handle: u64) -> DigitalActionHandle {
+        DigitalActionHandle(handle)
+    }
+
+    /// Returns the raw 64 bit value of the handle
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A handle to an analog (joystick, trigger) action
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnalogActionHandle(pub(crate) sys::ControllerAnalogActionHandle_t);
+
+impl AnalogActionHandle {
+    /// Creates an `AnalogActionHandle` from a raw 64 bit value.
+    pub fn from_raw(handle: u64) -> AnalogActionHandle {
+        AnalogActionHandle(handle)
+    }
+
+    /// Returns the raw 64 bit value of the handle
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The state of a digital action
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DigitalActionData {
+    /// Whether the action is currently pressed
+    pub state: bool,
+    /// Whether the action is bound in the active action set
+    pub active: bool,
+}
+
+/// The state of an analog action
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnalogActionData {
+    /// The current position along the x axis
+    pub x: f32,
+    /// The current position along the y axis
+    pub y: f32,
+    /// Whether the action is bound in the active action set
+    pub active: bool,
+}
+
+impl <Manager> Controller<Manager> {
+    /// Returns the raw `ISteamController` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamController {
+        self.controller
+    }
+
+    /// Initializes the steam controller interface.
+    ///
+    /// This must be called before any other controller method.
+    pub fn init(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamController_Init(self.controller)
+        }
+    }
+
+    /// Shuts down the steam controller interface.
+    pub fn shutdown(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamController_Shutdown(self.controller)
+        }
+    }
+
+    /// Synchronizes the controller state with steam.
+    pub fn run_frame(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamController_RunFrame(self.controller);
+        }
+    }
+
+    /// Returns the handles of all currently connected controllers.
+    pub fn connected_controllers(&self) -> Vec<ControllerHandle> {
+        unsafe {
+            let mut handles = vec![0; sys::STEAM_CONTROLLER_MAX_COUNT as usize];
+            let count = sys::SteamAPI_ISteamController_GetConnectedControllers(self.controller, handles.as_mut_ptr());
+            handles.truncate(count as usize);
+            handles.into_iter()
+                .map(InputHandle)
+                .collect()
+        }
+    }
+
+    /// Looks up the handle for an action set by name.
+    ///
+    /// Returns `None` if no action set with the name exists.
+    pub fn action_set_handle(&self, name: &str) -> Option<ActionSetHandle> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            let handle = sys::SteamAPI_ISteamController_GetActionSetHandle(self.controller, name.as_ptr());
+            if handle == 0 {
+                None
+            } else {
+                Some(ActionSetHandle(handle))
+            }
+        }
+    }
+
+    /// Switches the controller to the given action set
+    pub fn activate_action_set(&self, controller: ControllerHandle, action_set: ActionSetHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamController_ActivateActionSet(self.controller, controller.0, action_set.0);
+        }
+    }
+
+    /// Returns the currently active action set of the controller
+    pub fn current_action_set(&self, controller: ControllerHandle) -> ActionSetHandle {
+        unsafe {
+            ActionSetHandle(sys::SteamAPI_ISteamController_GetCurrentActionSet(self.controller, controller.0))
+        }
+    }
+
+    /// Looks up the handle of a digital action by name
+    pub fn digital_action_handle(&self, name: &str) -> DigitalActionHandle {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            DigitalActionHandle(sys::SteamAPI_ISteamController_GetDigitalActionHandle(self.controller, name.as_ptr()))
+        }
+    }
+
+    /// Returns the current state of a digital action
+    pub fn digital_action_data(&self, controller: ControllerHandle, action: DigitalActionHandle) -> DigitalActionData {
+        unsafe {
+            let data = sys::SteamAPI_ISteamController_GetDigitalActionData(self.controller, controller.0, action.0);
+            DigitalActionData {
+                state: data.bState,
+                active: data.bActive,
+            }
+        }
+    }
+
+    /// Looks up the handle of an analog action by name
+    pub fn analog_action_handle(&self, name: &str) -> AnalogActionHandle {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            AnalogActionHandle(sys::SteamAPI_ISteamController_GetAnalogActionHandle(self.controller, name.as_ptr()))
+        }
+    }
+
+    /// Returns the current state of an analog action
+    pub fn analog_action_data(&self, controller: ControllerHandle, action: AnalogActionHandle) -> AnalogActionData {
+        unsafe {
+            let data = sys::SteamAPI_ISteamController_GetAnalogActionData(self.controller, controller.0, action.0);
+            AnalogActionData {
+                x: data.x,
+                y: data.y,
+                active: data.bActive,
+            }
+        }
+    }
+
+    /// Stops the momentum of an analog action, e.g. a trackball
+    /// style mouse on a touch pad.
+    pub fn stop_analog_action_momentum(&self, controller: ControllerHandle, action: AnalogActionHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamController_StopAnalogActionMomentum(self.controller, controller.0, action.0);
+        }
+    }
+
+    /// Triggers a single haptic pulse on a steam controller pad
+    pub fn trigger_haptic_pulse(&self, controller: ControllerHandle, pad: ControllerPad, duration_micro_sec: u16) {
+        unsafe {
+            sys::SteamAPI_ISteamController_TriggerHapticPulse(self.controller, controller.0, pad.into(), duration_micro_sec);
+        }
+    }
+
+    /// Triggers the rumble motors on controllers that have them
+    pub fn trigger_vibration(&self, controller: ControllerHandle, left_speed: u16, right_speed: u16) {
+        unsafe {
+            sys::SteamAPI_ISteamController_TriggerVibration(self.controller, controller.0, left_speed, right_speed);
+        }
+    }
+
+    /// Opens the binding panel for the controller in the overlay
+    pub fn show_binding_panel(&self, controller: ControllerHandle) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamController_ShowBindingPanel(self.controller, controller.0)
+        }
+    }
+
+    /// Returns the type of the controller
+    pub fn input_type(&self, controller: ControllerHandle) -> InputType {
+        unsafe {
+            sys::SteamAPI_ISteamController_GetInputTypeForHandle(self.controller, controller.0).into()
+        }
+    }
+}
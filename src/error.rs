@@ -499,3 +499,14 @@ impl From<sys::EResult> for SteamError {
         }
     }
 }
+
+/// Converts a raw `EResult` code into an error.
+///
+/// Unknown codes are reported as `SteamError::Generic`.
+pub(crate) fn steam_error_from_raw(code: i64) -> SteamError {
+    // EResult has no variant 4 and ends at 115
+    if code < 2 || code == 4 || code > sys::EResult::k_EResultExistingUserCancelledLicense as i64 {
+        return SteamError::Generic;
+    }
+    unsafe { std::mem::transmute::<u32, sys::EResult>(code as u32) }.into()
+}
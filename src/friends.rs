@@ -46,6 +46,31 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Restrictions placed on the current user's account
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[repr(C)]
+    pub struct UserRestrictions: u32 {
+        const NONE         = 0x0000;
+        const UNKNOWN      = 0x0001;
+        const ANY_CHAT     = 0x0002;
+        const VOICE_CHAT   = 0x0004;
+        const GROUP_CHAT   = 0x0008;
+        const RATING       = 0x0010;
+        const GAME_INVITES = 0x0020;
+        const TRADING      = 0x0040;
+    }
+}
+
+/// A page of the users followed by the current user
+#[derive(Clone, Debug)]
+pub struct FollowingList {
+    /// The users in this page
+    pub users: Vec<SteamId>,
+    /// The total number of users followed
+    pub total_count: u32,
+}
+
 /// Access to the steam friends interface
 pub struct Friends<Manager> {
     pub(crate) friends: *mut sys::ISteamFriends,
@@ -53,6 +78,19 @@ pub struct Friends<Manager> {
 }
 
 impl <Manager> Friends<Manager> {
+    /// Returns the raw `ISteamFriends` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamFriends {
+        self.friends
+    }
+
 
     /// Returns the (display) name of the current user
     pub fn name(&self) -> String {
@@ -63,6 +101,22 @@ impl <Manager> Friends<Manager> {
         }
     }
 
+    /// Writes the (display) name of the current user into `buf`,
+    /// replacing its contents.
+    ///
+    /// Unlike `name` this doesn't allocate once `buf` is large enough.
+    pub fn name_into(&self, buf: &mut String) {
+        unsafe {
+            let name = sys::SteamAPI_ISteamFriends_GetPersonaName(self.friends);
+            copy_cstr_into(name, buf);
+        }
+    }
+
+    /// Returns the users matching any of the given flags.
+    ///
+    /// `FriendFlags::IMMEDIATE` returns regular friends while flags
+    /// such as `BLOCKED` or `IGNORED` can be combined to list other
+    /// relationships.
     pub fn get_friends(&self, flags: FriendFlags) -> Vec<Friend<Manager>> {
         unsafe {
             let count = sys::SteamAPI_ISteamFriends_GetFriendCount(self.friends, flags.bits() as _);
@@ -83,6 +137,34 @@ impl <Manager> Friends<Manager> {
         }
     }
 
+    /// Returns the number of users matching any of the given flags
+    /// without fetching them
+    pub fn friend_count(&self, flags: FriendFlags) -> usize {
+        unsafe {
+            let count = sys::SteamAPI_ISteamFriends_GetFriendCount(self.friends, flags.bits() as _);
+            count.max(0) as usize
+        }
+    }
+
+    /// Returns whether the user matches any of the given flags,
+    /// e.g. `FriendFlags::IMMEDIATE` to check whether they are a
+    /// regular friend
+    pub fn has_friend(&self, user: SteamId, flags: FriendFlags) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamFriends_HasFriend(self.friends, user.0, flags.bits() as _)
+        }
+    }
+
+    /// Returns the restrictions placed on the current user's account.
+    ///
+    /// Features such as chat should be hidden or disabled when the
+    /// matching restriction is set.
+    pub fn get_user_restrictions(&self) -> UserRestrictions {
+        unsafe {
+            UserRestrictions::from_bits_truncate(sys::SteamAPI_ISteamFriends_GetUserRestrictions(self.friends))
+        }
+    }
+
     pub fn get_friend(&self, friend: SteamId) -> Friend<Manager> {
         Friend {
             id: friend,
@@ -91,6 +173,37 @@ impl <Manager> Friends<Manager> {
         }
     }
 
+    /// Requests a page of the users the current user follows,
+    /// starting at `start_index`.
+    ///
+    /// Steam returns at most 50 users per a page, the next page can be
+    /// requested by passing the number of users received so far
+    /// until `total_count` is reached.
+    pub fn enumerate_following_list<F>(&self, start_index: u32, mut cb: F)
+        where F: FnMut(SResult<FollowingList>) + 'static + Send
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamFriends_EnumerateFollowingList(self.friends, start_index);
+            register_call_result::<sys::FriendsEnumerateFollowingList_t, _, _>(
+                &self.inner, api_call, CALLBACK_BASE_ID + 46,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        let count = (v.m_nResultsReturned.max(0) as usize).min(v.m_rgSteamID.len());
+                        Ok(FollowingList {
+                            users: v.m_rgSteamID[..count].iter()
+                                .map(|id| SteamId(id.m_steamid.m_unAll64Bits))
+                                .collect(),
+                            total_count: v.m_nTotalResultCount as u32,
+                        })
+                    })
+                });
+        }
+    }
+
     pub fn request_user_information(&self, user: SteamId, name_only: bool) {
         unsafe {
             sys::SteamAPI_ISteamFriends_RequestUserInformation(self.friends, user.0, name_only);
@@ -109,6 +222,14 @@ impl <Manager> Friends<Manager> {
         }
     }
 
+    /// Opens the overlay to the given dialog
+    pub fn activate_game_overlay(&self, dialog: OverlayDialog) {
+        unsafe {
+            let dialog = CString::new(dialog.as_str()).unwrap();
+            sys::SteamAPI_ISteamFriends_ActivateGameOverlay(self.friends, dialog.as_ptr());
+        }
+    }
+
     /// Opens up an invite dialog for the given lobby
     pub fn activate_invite_dialog(&self, lobby: LobbyId) {
         unsafe {
@@ -117,6 +238,41 @@ impl <Manager> Friends<Manager> {
     }
 }
 
+/// A dialog that the overlay can be opened to with
+/// `Friends::activate_game_overlay`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OverlayDialog {
+    /// The friends list
+    Friends,
+    /// The steam community
+    Community,
+    /// The players the user has recently played with
+    Players,
+    /// The steam settings
+    Settings,
+    /// The game's official group
+    OfficialGameGroup,
+    /// The user's stats for the game
+    Stats,
+    /// The user's achievements for the game
+    Achievements,
+}
+
+impl OverlayDialog {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            OverlayDialog::Friends => "friends",
+            OverlayDialog::Community => "community",
+            OverlayDialog::Players => "players",
+            OverlayDialog::Settings => "settings",
+            OverlayDialog::OfficialGameGroup => "officialgamegroup",
+            OverlayDialog::Stats => "stats",
+            OverlayDialog::Achievements => "achievements",
+        }
+    }
+}
+
 /// Information about a friend's current state in a game
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -149,7 +305,30 @@ unsafe impl Callback for PersonaStateChange {
         let val = &mut *(raw as *mut sys::PersonaStateChange_t);
         PersonaStateChange {
             steam_id: SteamId(val.m_ulSteamID),
-            flags: PersonaChange::from_bits_truncate(val.m_nChangeFlags as i32),
+            flags: PersonaChange::from_bits_truncate(val.m_nChangeFlags),
+        }
+    }
+}
+
+/// Called when the steam overlay is opened or closed.
+///
+/// Games should pause while the overlay is active where it makes
+/// sense to.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameOverlayActivated {
+    /// Whether the overlay is now open
+    pub active: bool,
+}
+
+unsafe impl Callback for GameOverlayActivated {
+    const ID: i32 = CALLBACK_BASE_ID + 31;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GameOverlayActivated_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GameOverlayActivated_t);
+        GameOverlayActivated {
+            active: val.m_bActive != 0,
         }
     }
 }
@@ -174,6 +353,33 @@ unsafe impl Callback for GameLobbyJoinRequested {
     }
 }
 
+/// Called when the user tries to join a friend's game through
+/// their rich presence while the game is already running.
+///
+/// If the game isn't running steam launches it with the connect
+/// string on the command line instead, see `Apps::launch_connect_string`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameRichPresenceJoinRequested {
+    /// The friend whose game the user is joining
+    pub friend_steam_id: SteamId,
+    /// The value of the friend's `connect` rich presence key
+    pub connect: String,
+}
+
+unsafe impl Callback for GameRichPresenceJoinRequested {
+    const ID: i32 = CALLBACK_BASE_ID + 37;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GameRichPresenceJoinRequested_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GameRichPresenceJoinRequested_t);
+        GameRichPresenceJoinRequested {
+            friend_steam_id: SteamId(val.m_steamIDFriend.m_steamid.m_unAll64Bits),
+            connect: CStr::from_ptr(val.m_rgchConnect.as_ptr()).to_string_lossy().into_owned(),
+        }
+    }
+}
+
 pub struct Friend<Manager> {
     id: SteamId,
     friends: *mut sys::ISteamFriends,
@@ -199,6 +405,42 @@ impl <Manager> Friend<Manager> {
         }
     }
 
+    /// Writes the name of the friend into `buf`, replacing its contents.
+    ///
+    /// Unlike `name` this doesn't allocate once `buf` is large enough.
+    pub fn name_into(&self, buf: &mut String) {
+        unsafe {
+            let name = sys::SteamAPI_ISteamFriends_GetFriendPersonaName(self.friends, self.id.0);
+            copy_cstr_into(name, buf);
+        }
+    }
+
+    /// Returns the rich presence value of the friend for the key.
+    ///
+    /// Returns `None` if the key isn't set.
+    pub fn rich_presence(&self, key: &str) -> Option<String> {
+        let key = CString::new(key).unwrap();
+        let mut value = String::new();
+        if self.rich_presence_into(&key, &mut value) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Writes the rich presence value of the friend for the key into
+    /// `buf`, replacing its contents.
+    ///
+    /// Unlike `rich_presence` this doesn't allocate once `buf` is large
+    /// enough. Returns false, leaving `buf` empty, if the key isn't set.
+    pub fn rich_presence_into(&self, key: &CStr, buf: &mut String) -> bool {
+        unsafe {
+            let value = sys::SteamAPI_ISteamFriends_GetFriendRichPresence(self.friends, self.id.0, key.as_ptr());
+            copy_cstr_into(value, buf);
+            !buf.is_empty()
+        }
+    }
+
     pub fn state(&self) -> FriendState {
         unsafe {
             let state = sys::SteamAPI_ISteamFriends_GetFriendPersonaState(self.friends, self.id.0);
@@ -215,13 +457,20 @@ impl <Manager> Friend<Manager> {
         }
     }
 
+    /// Returns the current user's relationship with this user
+    pub fn relationship(&self) -> FriendRelationship {
+        unsafe {
+            sys::SteamAPI_ISteamFriends_GetFriendRelationship(self.friends, self.id.0).into()
+        }
+    }
+
     /// Returns information about the game the player is current playing if any
     pub fn game_played(&self) -> Option<FriendGame> {
         unsafe {
             let mut info: sys::FriendGameInfo_t = std::mem::zeroed();
             if sys::SteamAPI_ISteamFriends_GetFriendGamePlayed(self.friends, self.id.0, &mut info) {
                 Some(FriendGame {
-                    game: GameId(std::mem::transmute(info.m_gameID)),
+                    game: GameId(info.m_gameID.__bindgen_anon_1.m_ulGameID),
                     game_address: info.m_unGameIP.into(),
                     game_port: info.m_usGamePort,
                     query_port: info.m_usQueryPort,
@@ -280,6 +529,40 @@ impl <Manager> Friend<Manager> {
     }
 }
 
+/// The relationship between the current user and another user
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FriendRelationship {
+    /// The users have no relationship
+    None,
+    /// The current user blocked the other user
+    Blocked,
+    /// The other user sent the current user a friend request
+    RequestRecipient,
+    /// The users are friends
+    Friend,
+    /// The current user sent the other user a friend request
+    RequestInitiator,
+    /// The current user ignores the other user
+    Ignored,
+    /// The other user ignores the current user
+    IgnoredFriend,
+}
+
+impl From<sys::EFriendRelationship> for FriendRelationship {
+    fn from(relationship: sys::EFriendRelationship) -> Self {
+        match relationship {
+            sys::EFriendRelationship::k_EFriendRelationshipBlocked => FriendRelationship::Blocked,
+            sys::EFriendRelationship::k_EFriendRelationshipRequestRecipient => FriendRelationship::RequestRecipient,
+            sys::EFriendRelationship::k_EFriendRelationshipFriend => FriendRelationship::Friend,
+            sys::EFriendRelationship::k_EFriendRelationshipRequestInitiator => FriendRelationship::RequestInitiator,
+            sys::EFriendRelationship::k_EFriendRelationshipIgnored => FriendRelationship::Ignored,
+            sys::EFriendRelationship::k_EFriendRelationshipIgnoredFriend => FriendRelationship::IgnoredFriend,
+            _ => FriendRelationship::None,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FriendState {
     Offline,
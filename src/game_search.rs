@@ -0,0 +1,448 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 5200;
+
+/// Access to the steam game search interface
+pub struct GameSearch<Manager> {
+    pub(crate) game_search: *mut sys::ISteamGameSearch,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+/// Errors that can be returned by the game search interface
+#[derive(Copy, Clone, Debug, Fail, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GameSearchError {
+    /// Returned when a search is started while one is already running
+    #[fail(display = "a search is already in progress")]
+    SearchAlreadyInProgress,
+    /// Returned when there is no search to act on
+    #[fail(display = "no search is in progress")]
+    NoSearchInProgress,
+    /// Returned when a lobby search is started by someone other than
+    /// the lobby's owner
+    #[fail(display = "the user is not the lobby leader")]
+    NotLobbyLeader,
+    /// Returned when no host could be found for the search
+    #[fail(display = "no host is available")]
+    NoHostAvailable,
+    /// Returned when the search parameters are invalid
+    #[fail(display = "the search parameters are invalid")]
+    SearchParamsInvalid,
+    /// Returned when the user is offline
+    #[fail(display = "the user is offline")]
+    Offline,
+    /// Returned when the user isn't authorized to search
+    #[fail(display = "the user is not authorized to search")]
+    NotAuthorized,
+    /// Returned for unknown errors
+    #[fail(display = "an unknown error occurred")]
+    Unknown,
+}
+
+fn game_search_result(code: sys::EGameSearchErrorCode_t) -> Result<(), GameSearchError> {
+    use sys::EGameSearchErrorCode_t::*;
+    Err(match code {
+        k_EGameSearchErrorCode_OK => return Ok(()),
+        k_EGameSearchErrorCode_Failed_Search_Already_In_Progress => GameSearchError::SearchAlreadyInProgress,
+        k_EGameSearchErrorCode_Failed_No_Search_In_Progress => GameSearchError::NoSearchInProgress,
+        k_EGameSearchErrorCode_Failed_Not_Lobby_Leader => GameSearchError::NotLobbyLeader,
+        k_EGameSearchErrorCode_Failed_No_Host_Available => GameSearchError::NoHostAvailable,
+        k_EGameSearchErrorCode_Failed_Search_Params_Invalid => GameSearchError::SearchParamsInvalid,
+        k_EGameSearchErrorCode_Failed_Offline => GameSearchError::Offline,
+        k_EGameSearchErrorCode_Failed_NotAuthorized => GameSearchError::NotAuthorized,
+        _ => GameSearchError::Unknown,
+    })
+}
+
+/// The outcome of a game for a single player, reported by the host
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlayerResult {
+    FailedToConnect,
+    Abandoned,
+    Kicked,
+    Incomplete,
+    Completed,
+}
+
+impl Into<sys::EPlayerResult_t> for PlayerResult {
+    fn into(self) -> sys::EPlayerResult_t {
+        match self {
+            PlayerResult::FailedToConnect => sys::EPlayerResult_t::k_EPlayerResultFailedToConnect,
+            PlayerResult::Abandoned => sys::EPlayerResult_t::k_EPlayerResultAbandoned,
+            PlayerResult::Kicked => sys::EPlayerResult_t::k_EPlayerResultKicked,
+            PlayerResult::Incomplete => sys::EPlayerResult_t::k_EPlayerResultIncomplete,
+            PlayerResult::Completed => sys::EPlayerResult_t::k_EPlayerResultCompleted,
+        }
+    }
+}
+
+/// Whether a player found by the host has accepted the game
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlayerAcceptState {
+    Unknown,
+    Accepted,
+    Declined,
+}
+
+impl <Manager> GameSearch<Manager> {
+    /// Returns the raw `ISteamGameSearch` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamGameSearch {
+        self.game_search
+    }
+
+    /// Adds a key that the search must match.
+    ///
+    /// A game matches if its value for the key is any of the
+    /// given values.
+    pub fn add_game_search_params(&self, key: &str, values: &[&str]) -> Result<(), GameSearchError> {
+        let key = CString::new(key).unwrap();
+        let values = CString::new(values.join(",")).unwrap();
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_AddGameSearchParams(
+                self.game_search, key.as_ptr(), values.as_ptr()
+            ))
+        }
+    }
+
+    /// Searches for a game for all members of the lobby.
+    ///
+    /// Must be called by the lobby's owner. Progress is reported
+    /// through `SearchForGameProgress` and `SearchForGameResult`
+    /// callbacks.
+    pub fn search_for_game_with_lobby(&self, lobby: LobbyId, min_players: i32, max_players: i32) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SearchForGameWithLobby(
+                self.game_search, lobby.0, min_players, max_players
+            ))
+        }
+    }
+
+    /// Searches for a game for the current user alone.
+    ///
+    /// Progress is reported through `SearchForGameProgress` and
+    /// `SearchForGameResult` callbacks.
+    pub fn search_for_game_solo(&self, min_players: i32, max_players: i32) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SearchForGameSolo(
+                self.game_search, min_players, max_players
+            ))
+        }
+    }
+
+    /// Accepts the game found by the search
+    pub fn accept_game(&self) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_AcceptGame(self.game_search))
+        }
+    }
+
+    /// Declines the game found by the search
+    pub fn decline_game(&self) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_DeclineGame(self.game_search))
+        }
+    }
+
+    /// Returns the connection details set by the host of the game
+    /// found by the search
+    pub fn retrieve_connection_details(&self, host: SteamId) -> Result<String, GameSearchError> {
+        unsafe {
+            let mut buffer = vec![0; 1024];
+            game_search_result(sys::SteamAPI_ISteamGameSearch_RetrieveConnectionDetails(
+                self.game_search, host.0,
+                buffer.as_mut_ptr(), buffer.len() as _
+            ))?;
+            Ok(CStr::from_ptr(buffer.as_ptr()).to_string_lossy().into_owned())
+        }
+    }
+
+    /// Ends the current search
+    pub fn end_game_search(&self) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_EndGameSearch(self.game_search))
+        }
+    }
+
+    /// Sets a key that searches can match against when hosting
+    pub fn set_game_host_params(&self, key: &str, value: &str) -> Result<(), GameSearchError> {
+        let key = CString::new(key).unwrap();
+        let value = CString::new(value).unwrap();
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SetGameHostParams(
+                self.game_search, key.as_ptr(), value.as_ptr()
+            ))
+        }
+    }
+
+    /// Sets the details players should use to connect to the host
+    pub fn set_connection_details(&self, details: &str) -> Result<(), GameSearchError> {
+        let details = CString::new(details).unwrap();
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SetConnectionDetails(
+                self.game_search, details.as_ptr(), details.as_bytes_with_nul().len() as _
+            ))
+        }
+    }
+
+    /// Starts looking for players to join the hosted game.
+    ///
+    /// Progress is reported through `RequestPlayersForGameProgress`,
+    /// `RequestPlayersForGameResult` and `RequestPlayersForGameFinalResult`
+    /// callbacks.
+    pub fn request_players_for_game(&self, min_players: i32, max_players: i32, max_team_size: i32) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_RequestPlayersForGame(
+                self.game_search, min_players, max_players, max_team_size
+            ))
+        }
+    }
+
+    /// Notifies the players that accepted the game that it has started
+    pub fn host_confirm_game_start(&self, game_id: u64) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_HostConfirmGameStart(self.game_search, game_id))
+        }
+    }
+
+    /// Stops looking for players to join the hosted game
+    pub fn cancel_request_players_for_game(&self) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_CancelRequestPlayersForGame(self.game_search))
+        }
+    }
+
+    /// Reports the outcome of the game for a player
+    pub fn submit_player_result(&self, game_id: u64, player: SteamId, result: PlayerResult) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_SubmitPlayerResult(
+                self.game_search, game_id, player.0, result.into()
+            ))
+        }
+    }
+
+    /// Ends the game once all player results have been submitted
+    pub fn end_game(&self, game_id: u64) -> Result<(), GameSearchError> {
+        unsafe {
+            game_search_result(sys::SteamAPI_ISteamGameSearch_EndGame(self.game_search, game_id))
+        }
+    }
+}
+
+/// Called periodically while searching for a game
+#[derive(Clone, Debug)]
+pub struct SearchForGameProgress {
+    pub search_id: u64,
+    pub result: SResult<()>,
+    /// The lobby the search was started for, if any
+    pub lobby: LobbyId,
+    /// The user that ended the search, if it has ended
+    pub ended_search: SteamId,
+    pub seconds_remaining_estimate: i32,
+    pub players_searching: i32,
+}
+
+unsafe impl Callback for SearchForGameProgress {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SearchForGameProgressCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SearchForGameProgressCallback_t);
+        SearchForGameProgress {
+            search_id: val.m_ullSearchID,
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            lobby: LobbyId(val.m_lobbyID.m_steamid.m_unAll64Bits),
+            ended_search: SteamId(val.m_steamIDEndedSearch.m_steamid.m_unAll64Bits),
+            seconds_remaining_estimate: val.m_nSecondsRemainingEstimate,
+            players_searching: val.m_cPlayersSearching,
+        }
+    }
+}
+
+/// Called when a game has been found for the search
+#[derive(Clone, Debug)]
+pub struct SearchForGameResult {
+    pub search_id: u64,
+    pub result: SResult<()>,
+    pub players_in_game: i32,
+    pub players_accepted_game: i32,
+    /// The host of the game, whose connection details can be
+    /// retrieved with `GameSearch::retrieve_connection_details`
+    pub host: SteamId,
+    /// Whether this is the last result for the search
+    pub final_callback: bool,
+}
+
+unsafe impl Callback for SearchForGameResult {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SearchForGameResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SearchForGameResultCallback_t);
+        SearchForGameResult {
+            search_id: val.m_ullSearchID,
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            players_in_game: val.m_nCountPlayersInGame,
+            players_accepted_game: val.m_nCountAcceptedGame,
+            host: SteamId(val.m_steamIDHost.m_steamid.m_unAll64Bits),
+            final_callback: val.m_bFinalCallback,
+        }
+    }
+}
+
+/// Called periodically on the host while looking for players
+#[derive(Clone, Debug)]
+pub struct RequestPlayersForGameProgress {
+    pub search_id: u64,
+    pub result: SResult<()>,
+}
+
+unsafe impl Callback for RequestPlayersForGameProgress {
+    const ID: i32 = CALLBACK_BASE_ID + 11;
+    const SIZE: i32 = ::std::mem::size_of::<sys::RequestPlayersForGameProgressCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::RequestPlayersForGameProgressCallback_t);
+        RequestPlayersForGameProgress {
+            search_id: val.m_ullSearchID,
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+        }
+    }
+}
+
+/// Called on the host for each player found for the game
+#[derive(Clone, Debug)]
+pub struct RequestPlayersForGameResult {
+    pub search_id: u64,
+    pub result: SResult<()>,
+    pub player: SteamId,
+    /// The lobby the player is searching with, if any
+    pub lobby: LobbyId,
+    pub accept_state: PlayerAcceptState,
+    pub player_index: i32,
+    pub total_players_found: i32,
+    pub total_players_accepted_game: i32,
+    pub suggested_team_index: i32,
+    pub game_id: u64,
+}
+
+unsafe impl Callback for RequestPlayersForGameResult {
+    const ID: i32 = CALLBACK_BASE_ID + 12;
+    const SIZE: i32 = ::std::mem::size_of::<sys::RequestPlayersForGameResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::RequestPlayersForGameResultCallback_t);
+        RequestPlayersForGameResult {
+            search_id: val.m_ullSearchID,
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            player: SteamId(val.m_SteamIDPlayerFound.m_steamid.m_unAll64Bits),
+            lobby: LobbyId(val.m_SteamIDLobby.m_steamid.m_unAll64Bits),
+            accept_state: match val.m_ePlayerAcceptState {
+                sys::RequestPlayersForGameResultCallback_t_PlayerAcceptState_t::k_EStatePlayerAccepted => PlayerAcceptState::Accepted,
+                sys::RequestPlayersForGameResultCallback_t_PlayerAcceptState_t::k_EStatePlayerDeclined => PlayerAcceptState::Declined,
+                _ => PlayerAcceptState::Unknown,
+            },
+            player_index: val.m_nPlayerIndex,
+            total_players_found: val.m_nTotalPlayersFound,
+            total_players_accepted_game: val.m_nTotalPlayersAcceptedGame,
+            suggested_team_index: val.m_nSuggestedTeamIndex,
+            game_id: val.m_ullUniqueGameID,
+        }
+    }
+}
+
+/// Called on the host once all players have been found
+#[derive(Clone, Debug)]
+pub struct RequestPlayersForGameFinalResult {
+    pub search_id: u64,
+    pub result: SResult<()>,
+    pub game_id: u64,
+}
+
+unsafe impl Callback for RequestPlayersForGameFinalResult {
+    const ID: i32 = CALLBACK_BASE_ID + 13;
+    const SIZE: i32 = ::std::mem::size_of::<sys::RequestPlayersForGameFinalResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::RequestPlayersForGameFinalResultCallback_t);
+        RequestPlayersForGameFinalResult {
+            search_id: val.m_ullSearchID,
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            game_id: val.m_ullUniqueGameID,
+        }
+    }
+}
+
+/// Called when a result submitted with `GameSearch::submit_player_result`
+/// has been processed
+#[derive(Clone, Debug)]
+pub struct SubmitPlayerResultResult {
+    pub result: SResult<()>,
+    pub game_id: u64,
+    pub player: SteamId,
+}
+
+unsafe impl Callback for SubmitPlayerResultResult {
+    const ID: i32 = CALLBACK_BASE_ID + 14;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SubmitPlayerResultResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SubmitPlayerResultResultCallback_t);
+        SubmitPlayerResultResult {
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            game_id: val.ullUniqueGameID,
+            player: SteamId(val.steamIDPlayer.m_steamid.m_unAll64Bits),
+        }
+    }
+}
+
+/// Called when a game ended with `GameSearch::end_game` has been
+/// processed
+#[derive(Clone, Debug)]
+pub struct EndGameResult {
+    pub result: SResult<()>,
+    pub game_id: u64,
+}
+
+unsafe impl Callback for EndGameResult {
+    const ID: i32 = CALLBACK_BASE_ID + 15;
+    const SIZE: i32 = ::std::mem::size_of::<sys::EndGameResultCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::EndGameResultCallback_t);
+        EndGameResult {
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+            game_id: val.ullUniqueGameID,
+        }
+    }
+}
@@ -0,0 +1,640 @@
+use super::*;
+use std::path::Path;
+
+const CALLBACK_BASE_ID: i32 = 4500;
+
+/// Access to the steam html surface interface
+///
+/// Allows rendering web pages offscreen into a texture
+/// supplied by steam.
+pub struct HtmlSurface<Manager> {
+    pub(crate) html: *mut sys::ISteamHTMLSurface,
+    pub(crate) inner: Arc<Inner<Manager>>,
+}
+
+/// A handle to a browser created with `HtmlSurface::create_browser`
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BrowserHandle(pub(crate) sys::HHTMLBrowser);
+
+impl BrowserHandle {
+    /// Creates a `BrowserHandle` from a raw 32 bit value.
+    pub fn from_raw(handle: u32) -> BrowserHandle {
+        BrowserHandle(handle)
+    }
+
+    /// Returns the raw 32 bit value of the handle
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+}
+
+/// A mouse button that can be injected into a browser
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HtmlMouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+impl Into<sys::ISteamHTMLSurface_EHTMLMouseButton> for HtmlMouseButton {
+    fn into(self) -> sys::ISteamHTMLSurface_EHTMLMouseButton {
+        match self {
+            HtmlMouseButton::Left => sys::ISteamHTMLSurface_EHTMLMouseButton::eHTMLMouseButton_Left,
+            HtmlMouseButton::Right => sys::ISteamHTMLSurface_EHTMLMouseButton::eHTMLMouseButton_Right,
+            HtmlMouseButton::Middle => sys::ISteamHTMLSurface_EHTMLMouseButton::eHTMLMouseButton_Middle,
+        }
+    }
+}
+
+bitflags! {
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[repr(C)]
+    pub struct HtmlKeyModifiers: u32 {
+        const NONE       = 0x0000;
+        const ALT_DOWN   = 0x0001;
+        const CTRL_DOWN  = 0x0002;
+        const SHIFT_DOWN = 0x0004;
+    }
+}
+
+impl HtmlKeyModifiers {
+    fn to_sys(self) -> sys::ISteamHTMLSurface_EHTMLKeyModifiers {
+        sys::ISteamHTMLSurface_EHTMLKeyModifiers(self.bits())
+    }
+}
+
+impl <Manager> HtmlSurface<Manager> {
+    /// Returns the raw `ISteamHTMLSurface` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamHTMLSurface {
+        self.html
+    }
+
+    /// Initializes the html surface interface.
+    ///
+    /// This must be called before any browsers are created.
+    pub fn init(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_Init(self.html)
+        }
+    }
+
+    /// Shuts down the html surface interface, freeing any
+    /// browsers that are still open.
+    pub fn shutdown(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_Shutdown(self.html)
+        }
+    }
+
+    /// Creates a new browser.
+    ///
+    /// The callback is called with the handle of the browser once it
+    /// is ready to load pages. Optionally a custom user agent and a
+    /// stylesheet to apply to every page can be provided.
+    ///
+    /// The browser must be freed with `remove_browser` once it is no
+    /// longer needed.
+    pub fn create_browser<F>(&self, user_agent: Option<&str>, user_css: Option<&str>, mut cb: F)
+        where F: FnMut(SResult<BrowserHandle>) + 'static + Send
+    {
+        let user_agent = user_agent.map(|v| CString::new(v).unwrap());
+        let user_css = user_css.map(|v| CString::new(v).unwrap());
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamHTMLSurface_CreateBrowser(
+                self.html,
+                user_agent.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                user_css.as_ref().map_or(std::ptr::null(), |v| v.as_ptr())
+            );
+            register_call_result::<sys::HTML_BrowserReady_t, _, _>(
+                &self.inner, api_call, CALLBACK_BASE_ID + 1,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else {
+                        Ok(BrowserHandle(v.unBrowserHandle))
+                    })
+                }
+            );
+        }
+    }
+
+    /// Frees a browser created with `create_browser`.
+    ///
+    /// An `HtmlCloseBrowser` callback is not fired for browsers
+    /// removed this way.
+    pub fn remove_browser(&self, browser: BrowserHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_RemoveBrowser(self.html, browser.0);
+        }
+    }
+
+    /// Navigates the browser to the given url.
+    ///
+    /// If post data is provided the page is requested with a `POST`
+    /// request instead of a `GET` request.
+    pub fn load_url(&self, browser: BrowserHandle, url: &str, post_data: Option<&str>) {
+        let url = CString::new(url).unwrap();
+        let post_data = post_data.map(|v| CString::new(v).unwrap());
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_LoadURL(
+                self.html, browser.0,
+                url.as_ptr(),
+                post_data.as_ref().map_or(std::ptr::null(), |v| v.as_ptr())
+            );
+        }
+    }
+
+    /// Stops loading the current page
+    pub fn stop_load(&self, browser: BrowserHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_StopLoad(self.html, browser.0);
+        }
+    }
+
+    /// Reloads the current page
+    pub fn reload(&self, browser: BrowserHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_Reload(self.html, browser.0);
+        }
+    }
+
+    /// Navigates back in the browser's history
+    pub fn go_back(&self, browser: BrowserHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_GoBack(self.html, browser.0);
+        }
+    }
+
+    /// Navigates forward in the browser's history
+    pub fn go_forward(&self, browser: BrowserHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_GoForward(self.html, browser.0);
+        }
+    }
+
+    /// Sets the size of the browser's viewport in pixels
+    pub fn set_size(&self, browser: BrowserHandle, width: u32, height: u32) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_SetSize(self.html, browser.0, width, height);
+        }
+    }
+
+    /// Injects a mouse button press into the browser
+    pub fn mouse_down(&self, browser: BrowserHandle, button: HtmlMouseButton) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_MouseDown(self.html, browser.0, button.into());
+        }
+    }
+
+    /// Injects a mouse button release into the browser
+    pub fn mouse_up(&self, browser: BrowserHandle, button: HtmlMouseButton) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_MouseUp(self.html, browser.0, button.into());
+        }
+    }
+
+    /// Injects a mouse button double click into the browser
+    pub fn mouse_double_click(&self, browser: BrowserHandle, button: HtmlMouseButton) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_MouseDoubleClick(self.html, browser.0, button.into());
+        }
+    }
+
+    /// Moves the mouse to the given position relative to the top
+    /// left of the browser's viewport
+    pub fn mouse_move(&self, browser: BrowserHandle, x: i32, y: i32) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_MouseMove(self.html, browser.0, x, y);
+        }
+    }
+
+    /// Injects a mouse wheel scroll into the browser
+    pub fn mouse_wheel(&self, browser: BrowserHandle, delta: i32) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_MouseWheel(self.html, browser.0, delta);
+        }
+    }
+
+    /// Injects a key press into the browser.
+    ///
+    /// `native_key_code` is the platform's virtual key code. System keys,
+    /// such as those pressed while alt is held on windows, should set
+    /// `is_system_key`.
+    pub fn key_down(&self, browser: BrowserHandle, native_key_code: u32, modifiers: HtmlKeyModifiers, is_system_key: bool) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_KeyDown(
+                self.html, browser.0,
+                native_key_code, modifiers.to_sys(), is_system_key
+            );
+        }
+    }
+
+    /// Injects a key release into the browser
+    pub fn key_up(&self, browser: BrowserHandle, native_key_code: u32, modifiers: HtmlKeyModifiers) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_KeyUp(self.html, browser.0, native_key_code, modifiers.to_sys());
+        }
+    }
+
+    /// Injects a typed character into the browser
+    pub fn key_char(&self, browser: BrowserHandle, c: char, modifiers: HtmlKeyModifiers) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_KeyChar(self.html, browser.0, c as u32, modifiers.to_sys());
+        }
+    }
+
+    /// Scrolls the page horizontally to the given pixel offset
+    pub fn set_horizontal_scroll(&self, browser: BrowserHandle, scroll: u32) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_SetHorizontalScroll(self.html, browser.0, scroll);
+        }
+    }
+
+    /// Scrolls the page vertically to the given pixel offset
+    pub fn set_vertical_scroll(&self, browser: BrowserHandle, scroll: u32) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_SetVerticalScroll(self.html, browser.0, scroll);
+        }
+    }
+
+    /// Sets whether the browser has keyboard focus
+    pub fn set_key_focus(&self, browser: BrowserHandle, has_focus: bool) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_SetKeyFocus(self.html, browser.0, has_focus);
+        }
+    }
+
+    /// Searches the page for the given text.
+    ///
+    /// `currently_in_find` should be set when continuing a previous
+    /// search to move to the next match. The results are reported in
+    /// an `HtmlSearchResults` callback.
+    pub fn find(&self, browser: BrowserHandle, text: &str, currently_in_find: bool, reverse: bool) {
+        let text = CString::new(text).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_Find(self.html, browser.0, text.as_ptr(), currently_in_find, reverse);
+        }
+    }
+
+    /// Cancels the current search and clears any highlighted matches
+    pub fn stop_find(&self, browser: BrowserHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_StopFind(self.html, browser.0);
+        }
+    }
+
+    /// Responds to an `HtmlJsAlert` or `HtmlJsConfirm` callback.
+    ///
+    /// For alerts the result is ignored.
+    pub fn js_dialog_response(&self, browser: BrowserHandle, result: bool) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_JSDialogResponse(self.html, browser.0, result);
+        }
+    }
+
+    /// Responds to an `HtmlFileOpenDialog` callback with the files
+    /// the user selected, or `None` if the dialog was cancelled.
+    pub fn file_load_dialog_response(&self, browser: BrowserHandle, files: Option<&[&Path]>) {
+        let files = files.map(|files| files.iter()
+            .map(|v| CString::new(&*v.to_string_lossy()).unwrap())
+            .collect::<Vec<_>>());
+        let mut ptrs = files.as_ref().map(|files| files.iter()
+            .map(|v| v.as_ptr())
+            .chain(Some(std::ptr::null()))
+            .collect::<Vec<_>>());
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_FileLoadDialogResponse(
+                self.html, browser.0,
+                ptrs.as_mut().map_or(std::ptr::null_mut(), |v| v.as_mut_ptr())
+            );
+        }
+    }
+
+    /// Allows or denies a navigation requested by a page.
+    ///
+    /// This must be called in response to every `HtmlStartRequest`
+    /// callback, otherwise the browser will stall.
+    pub fn allow_start_request(&self, browser: BrowserHandle, allowed: bool) {
+        unsafe {
+            sys::SteamAPI_ISteamHTMLSurface_AllowStartRequest(self.html, browser.0, allowed);
+        }
+    }
+}
+
+/// Called when a browser has new content that needs to be drawn
+#[derive(Clone, Debug)]
+pub struct HtmlNeedsPaint {
+    /// The browser that needs to be painted
+    pub browser: BrowserHandle,
+    /// The page contents as BGRA pixels, `width * height * 4` bytes long
+    pub bgra: Vec<u8>,
+    /// The width of the page contents in pixels
+    pub width: u32,
+    /// The height of the page contents in pixels
+    pub height: u32,
+    /// The x position of the area that changed since the last paint
+    pub update_x: u32,
+    /// The y position of the area that changed since the last paint
+    pub update_y: u32,
+    /// The width of the area that changed since the last paint
+    pub update_width: u32,
+    /// The height of the area that changed since the last paint
+    pub update_height: u32,
+    /// The horizontal scroll position of the page
+    pub scroll_x: u32,
+    /// The vertical scroll position of the page
+    pub scroll_y: u32,
+    /// The current zoom of the page
+    pub page_scale: f32,
+    /// Incremented each time a new page is loaded
+    pub page_serial: u32,
+}
+
+unsafe impl Callback for HtmlNeedsPaint {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_NeedsPaint_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_NeedsPaint_t);
+        let len = val.unWide as usize * val.unTall as usize * 4;
+        // The buffer is only valid for the duration of the callback
+        let bgra = if val.pBGRA.is_null() {
+            Vec::new()
+        } else {
+            std::slice::from_raw_parts(val.pBGRA as *const u8, len).to_vec()
+        };
+        HtmlNeedsPaint {
+            browser: BrowserHandle(val.unBrowserHandle),
+            bgra,
+            width: val.unWide,
+            height: val.unTall,
+            update_x: val.unUpdateX,
+            update_y: val.unUpdateY,
+            update_width: val.unUpdateWide,
+            update_height: val.unUpdateTall,
+            scroll_x: val.unScrollX,
+            scroll_y: val.unScrollY,
+            page_scale: val.flPageScale,
+            page_serial: val.unPageSerial,
+        }
+    }
+}
+
+/// Called when a browser wants to navigate to a new page.
+///
+/// The navigation must be allowed or denied with
+/// `HtmlSurface::allow_start_request`.
+#[derive(Clone, Debug)]
+pub struct HtmlStartRequest {
+    /// The browser that is navigating
+    pub browser: BrowserHandle,
+    /// The url being navigated to
+    pub url: String,
+    /// The target frame of the navigation
+    pub target: String,
+    /// The post data sent with the request, if any
+    pub post_data: String,
+    /// Whether the navigation is the result of a redirect
+    pub is_redirect: bool,
+}
+
+unsafe impl Callback for HtmlStartRequest {
+    const ID: i32 = CALLBACK_BASE_ID + 3;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_StartRequest_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_StartRequest_t);
+        HtmlStartRequest {
+            browser: BrowserHandle(val.unBrowserHandle),
+            url: html_string(val.pchURL),
+            target: html_string(val.pchTarget),
+            post_data: html_string(val.pchPostData),
+            is_redirect: val.bIsRedirect,
+        }
+    }
+}
+
+/// Called when a browser has been closed by the page, for example
+/// by calling `window.close()`.
+///
+/// The handle is no longer valid after this is received.
+#[derive(Clone, Debug)]
+pub struct HtmlCloseBrowser {
+    /// The browser that was closed
+    pub browser: BrowserHandle,
+}
+
+unsafe impl Callback for HtmlCloseBrowser {
+    const ID: i32 = CALLBACK_BASE_ID + 4;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_CloseBrowser_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_CloseBrowser_t);
+        HtmlCloseBrowser {
+            browser: BrowserHandle(val.unBrowserHandle),
+        }
+    }
+}
+
+/// Called when a browser has finished loading a page
+#[derive(Clone, Debug)]
+pub struct HtmlFinishedRequest {
+    /// The browser that loaded the page
+    pub browser: BrowserHandle,
+    /// The url of the page
+    pub url: String,
+    /// The title of the page
+    pub page_title: String,
+}
+
+unsafe impl Callback for HtmlFinishedRequest {
+    const ID: i32 = CALLBACK_BASE_ID + 6;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_FinishedRequest_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_FinishedRequest_t);
+        HtmlFinishedRequest {
+            browser: BrowserHandle(val.unBrowserHandle),
+            url: html_string(val.pchURL),
+            page_title: html_string(val.pchPageTitle),
+        }
+    }
+}
+
+/// Called with the results of a search started with `HtmlSurface::find`
+#[derive(Clone, Debug)]
+pub struct HtmlSearchResults {
+    /// The browser that was searched
+    pub browser: BrowserHandle,
+    /// The number of matches on the page
+    pub results: u32,
+    /// The index of the currently highlighted match
+    pub current_match: u32,
+}
+
+unsafe impl Callback for HtmlSearchResults {
+    const ID: i32 = CALLBACK_BASE_ID + 9;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_SearchResults_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_SearchResults_t);
+        HtmlSearchResults {
+            browser: BrowserHandle(val.unBrowserHandle),
+            results: val.unResults,
+            current_match: val.unCurrentMatch,
+        }
+    }
+}
+
+/// Called when a page wants to open a link in a new tab
+#[derive(Clone, Debug)]
+pub struct HtmlOpenLinkInNewTab {
+    /// The browser the link was opened from
+    pub browser: BrowserHandle,
+    /// The url of the link
+    pub url: String,
+}
+
+unsafe impl Callback for HtmlOpenLinkInNewTab {
+    const ID: i32 = CALLBACK_BASE_ID + 7;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_OpenLinkInNewTab_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_OpenLinkInNewTab_t);
+        HtmlOpenLinkInNewTab {
+            browser: BrowserHandle(val.unBrowserHandle),
+            url: html_string(val.pchURL),
+        }
+    }
+}
+
+/// Called when a page displays a javascript alert.
+///
+/// The dialog must be dismissed with `HtmlSurface::js_dialog_response`.
+#[derive(Clone, Debug)]
+pub struct HtmlJsAlert {
+    /// The browser showing the alert
+    pub browser: BrowserHandle,
+    /// The message of the alert
+    pub message: String,
+}
+
+unsafe impl Callback for HtmlJsAlert {
+    const ID: i32 = CALLBACK_BASE_ID + 14;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_JSAlert_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_JSAlert_t);
+        HtmlJsAlert {
+            browser: BrowserHandle(val.unBrowserHandle),
+            message: html_string(val.pchMessage),
+        }
+    }
+}
+
+/// Called when a page displays a javascript confirmation dialog.
+///
+/// The user's choice must be passed to `HtmlSurface::js_dialog_response`.
+#[derive(Clone, Debug)]
+pub struct HtmlJsConfirm {
+    /// The browser showing the dialog
+    pub browser: BrowserHandle,
+    /// The message of the dialog
+    pub message: String,
+}
+
+unsafe impl Callback for HtmlJsConfirm {
+    const ID: i32 = CALLBACK_BASE_ID + 15;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_JSConfirm_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_JSConfirm_t);
+        HtmlJsConfirm {
+            browser: BrowserHandle(val.unBrowserHandle),
+            message: html_string(val.pchMessage),
+        }
+    }
+}
+
+/// Called when a page wants the user to pick files to upload.
+///
+/// The selected files must be passed to
+/// `HtmlSurface::file_load_dialog_response`.
+#[derive(Clone, Debug)]
+pub struct HtmlFileOpenDialog {
+    /// The browser requesting the files
+    pub browser: BrowserHandle,
+    /// The title of the dialog
+    pub title: String,
+    /// The file initially selected in the dialog
+    pub initial_file: String,
+}
+
+unsafe impl Callback for HtmlFileOpenDialog {
+    const ID: i32 = CALLBACK_BASE_ID + 16;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_FileOpenDialog_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_FileOpenDialog_t);
+        HtmlFileOpenDialog {
+            browser: BrowserHandle(val.unBrowserHandle),
+            title: html_string(val.pchTitle),
+            initial_file: html_string(val.pchInitialFile),
+        }
+    }
+}
+
+/// Called when a page opens a popup window.
+///
+/// Steam doesn't create a browser for the popup, a new one can be
+/// created with `HtmlSurface::create_browser` and pointed at the url.
+#[derive(Clone, Debug)]
+pub struct HtmlNewWindow {
+    /// The browser that opened the popup
+    pub browser: BrowserHandle,
+    /// The url the popup wants to load
+    pub url: String,
+    /// The requested x position of the popup
+    pub x: u32,
+    /// The requested y position of the popup
+    pub y: u32,
+    /// The requested width of the popup
+    pub width: u32,
+    /// The requested height of the popup
+    pub height: u32,
+}
+
+unsafe impl Callback for HtmlNewWindow {
+    const ID: i32 = CALLBACK_BASE_ID + 21;
+    const SIZE: i32 = ::std::mem::size_of::<sys::HTML_NewWindow_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::HTML_NewWindow_t);
+        HtmlNewWindow {
+            browser: BrowserHandle(val.unBrowserHandle),
+            url: html_string(val.pchURL),
+            x: val.unX,
+            y: val.unY,
+            width: val.unWide,
+            height: val.unTall,
+        }
+    }
+}
+
+unsafe fn html_string(ptr: *const libc::c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(ptr).to_string_lossy().into_owned()
+    }
+}
@@ -0,0 +1,297 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 2100;
+
+/// Access to the steam http interface
+pub struct HTTP<Manager> {
+    pub(crate) http: *mut sys::ISteamHTTP,
+    pub(crate) inner: Arc<Inner<Manager>>,
+}
+
+/// The method of a http request
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HTTPMethod {
+    Get,
+    Head,
+    Post,
+    Put,
+    Delete,
+    Options,
+    Patch,
+}
+
+impl Into<sys::EHTTPMethod> for HTTPMethod {
+    fn into(self) -> sys::EHTTPMethod {
+        match self {
+            HTTPMethod::Get => sys::EHTTPMethod::k_EHTTPMethodGET,
+            HTTPMethod::Head => sys::EHTTPMethod::k_EHTTPMethodHEAD,
+            HTTPMethod::Post => sys::EHTTPMethod::k_EHTTPMethodPOST,
+            HTTPMethod::Put => sys::EHTTPMethod::k_EHTTPMethodPUT,
+            HTTPMethod::Delete => sys::EHTTPMethod::k_EHTTPMethodDELETE,
+            HTTPMethod::Options => sys::EHTTPMethod::k_EHTTPMethodOPTIONS,
+            HTTPMethod::Patch => sys::EHTTPMethod::k_EHTTPMethodPATCH,
+        }
+    }
+}
+
+impl <Manager> HTTP<Manager> {
+    /// Returns the raw `ISteamHTTP` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamHTTP {
+        self.http
+    }
+
+    /// Creates a new http request to the given absolute url.
+    ///
+    /// The request isn't sent until `HTTPRequest::send` is called.
+    /// Fails with `SteamError::InvalidParameter` if steam rejects the
+    /// request, e.g. because the url isn't absolute.
+    pub fn request(&self, method: HTTPMethod, url: &str) -> SResult<HTTPRequest<Manager>> {
+        unsafe {
+            let url = CString::new(url).unwrap();
+            let handle = sys::SteamAPI_ISteamHTTP_CreateHTTPRequest(self.http, method.into(), url.as_ptr());
+            if handle == sys::INVALID_HTTPREQUEST_HANDLE {
+                return Err(SteamError::InvalidParameter);
+            }
+            Ok(HTTPRequest {
+                http: self.http,
+                handle,
+                inner: self.inner.clone(),
+            })
+        }
+    }
+}
+
+/// A http request that hasn't been sent yet
+///
+/// The request is released if it is dropped without being sent.
+pub struct HTTPRequest<Manager> {
+    http: *mut sys::ISteamHTTP,
+    handle: sys::HTTPRequestHandle,
+    inner: Arc<Inner<Manager>>,
+}
+
+impl <Manager> HTTPRequest<Manager> {
+    /// Sets a header to send with the request
+    #[must_use]
+    pub fn header(self, name: &str, value: &str) -> Self {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            let value = CString::new(value).unwrap();
+            let ok = sys::SteamAPI_ISteamHTTP_SetHTTPRequestHeaderValue(self.http, self.handle, name.as_ptr(), value.as_ptr());
+            debug_assert!(ok);
+        }
+        self
+    }
+
+    /// Sets a parameter of the request.
+    ///
+    /// For `GET` requests this is added to the query string,
+    /// otherwise it is sent in the body.
+    #[must_use]
+    pub fn parameter(self, name: &str, value: &str) -> Self {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            let value = CString::new(value).unwrap();
+            let ok = sys::SteamAPI_ISteamHTTP_SetHTTPRequestGetOrPostParameter(self.http, self.handle, name.as_ptr(), value.as_ptr());
+            debug_assert!(ok);
+        }
+        self
+    }
+
+    /// Sets the raw body of the request, replacing any parameters
+    #[must_use]
+    pub fn body(self, content_type: &str, body: &[u8]) -> Self {
+        unsafe {
+            let content_type = CString::new(content_type).unwrap();
+            let ok = sys::SteamAPI_ISteamHTTP_SetHTTPRequestRawPostBody(
+                self.http, self.handle, content_type.as_ptr(),
+                body.as_ptr() as *mut _, body.len() as _,
+            );
+            debug_assert!(ok);
+        }
+        self
+    }
+
+    /// Sets the total time in milliseconds the request may take
+    /// before it is aborted, including time spent queued.
+    #[must_use]
+    pub fn absolute_timeout_ms(self, milliseconds: u32) -> Self {
+        unsafe {
+            let ok = sys::SteamAPI_ISteamHTTP_SetHTTPRequestAbsoluteTimeoutMS(self.http, self.handle, milliseconds);
+            debug_assert!(ok);
+        }
+        self
+    }
+
+    /// Sets how long in seconds the request may go without any
+    /// network activity before it is aborted.
+    ///
+    /// Defaults to 60 seconds.
+    #[must_use]
+    pub fn network_activity_timeout(self, seconds: u32) -> Self {
+        unsafe {
+            let ok = sys::SteamAPI_ISteamHTTP_SetHTTPRequestNetworkActivityTimeout(self.http, self.handle, seconds);
+            debug_assert!(ok);
+        }
+        self
+    }
+
+    /// Sets whether the request requires a certificate from a
+    /// trusted authority for https urls.
+    #[must_use]
+    pub fn requires_verified_certificate(self, required: bool) -> Self {
+        unsafe {
+            let ok = sys::SteamAPI_ISteamHTTP_SetHTTPRequestRequiresVerifiedCertificate(self.http, self.handle, required);
+            debug_assert!(ok);
+        }
+        self
+    }
+
+    /// Tags the request with a value that is returned with the
+    /// response.
+    ///
+    /// Useful for matching a response back to whatever made the
+    /// request.
+    #[must_use]
+    pub fn context_value(self, value: u64) -> Self {
+        unsafe {
+            let ok = sys::SteamAPI_ISteamHTTP_SetHTTPRequestContextValue(self.http, self.handle, value);
+            debug_assert!(ok);
+        }
+        self
+    }
+
+    /// Sends the request, calling the callback once the response
+    /// has been received.
+    pub fn send<F>(self, mut cb: F)
+        where F: FnMut(Result<HTTPResponse, SteamError>) + 'static + Send
+    {
+        unsafe {
+            let mut api_call = 0;
+            let sent = sys::SteamAPI_ISteamHTTP_SendHTTPRequest(self.http, self.handle, &mut api_call);
+            if !sent {
+                cb(Err(SteamError::InvalidParameter));
+                return;
+            }
+            // The response now owns the handle. Raw pointers aren't
+            // `Send` so the interface is carried as an address.
+            let http = self.http as usize;
+            let handle = self.handle;
+            let inner = std::ptr::read(&self.inner);
+            std::mem::forget(self);
+            register_call_result::<sys::HTTPRequestCompleted_t, _, _>(
+                &inner, api_call, CALLBACK_BASE_ID + 1,
+                move |v, io_error| {
+                    let response = HTTPResponse {
+                        http: http as *mut sys::ISteamHTTP,
+                        handle,
+                        context_value: v.m_ulContextValue,
+                        status_code: v.m_eStatusCode as u32 as u16,
+                    };
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else if !v.m_bRequestSuccessful {
+                        if response.was_timed_out() {
+                            Err(SteamError::Timeout)
+                        } else {
+                            Err(SteamError::NoConnection)
+                        }
+                    } else {
+                        Ok(response)
+                    })
+                });
+        }
+    }
+}
+
+impl <Manager> Drop for HTTPRequest<Manager> {
+    fn drop(&mut self) {
+        unsafe {
+            sys::SteamAPI_ISteamHTTP_ReleaseHTTPRequest(self.http, self.handle);
+        }
+    }
+}
+
+/// The response to a http request
+///
+/// The request is released when this is dropped.
+pub struct HTTPResponse {
+    http: *mut sys::ISteamHTTP,
+    handle: sys::HTTPRequestHandle,
+    context_value: u64,
+    status_code: u16,
+}
+
+impl HTTPResponse {
+    /// Returns the http status code of the response
+    pub fn status_code(&self) -> u16 {
+        self.status_code
+    }
+
+    /// Returns the value set with `HTTPRequest::context_value`
+    pub fn context_value(&self) -> u64 {
+        self.context_value
+    }
+
+    /// Returns whether the request timed out
+    pub fn was_timed_out(&self) -> bool {
+        unsafe {
+            let mut timed_out = false;
+            sys::SteamAPI_ISteamHTTP_GetHTTPRequestWasTimedOut(self.http, self.handle, &mut timed_out);
+            timed_out
+        }
+    }
+
+    /// Returns the value of the named response header if it
+    /// was sent.
+    pub fn header(&self, name: &str) -> Option<String> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            let mut size = 0;
+            if !sys::SteamAPI_ISteamHTTP_GetHTTPResponseHeaderSize(self.http, self.handle, name.as_ptr(), &mut size) {
+                return None;
+            }
+            let mut buffer = vec![0u8; size as usize];
+            if !sys::SteamAPI_ISteamHTTP_GetHTTPResponseHeaderValue(self.http, self.handle, name.as_ptr(), buffer.as_mut_ptr(), size) {
+                return None;
+            }
+            // The value is nul terminated
+            if let Some(end) = buffer.iter().position(|&b| b == 0) {
+                buffer.truncate(end);
+            }
+            Some(String::from_utf8_lossy(&buffer).into_owned())
+        }
+    }
+
+    /// Returns the body of the response
+    pub fn body(&self) -> Vec<u8> {
+        unsafe {
+            let mut size = 0;
+            if !sys::SteamAPI_ISteamHTTP_GetHTTPResponseBodySize(self.http, self.handle, &mut size) {
+                return Vec::new();
+            }
+            let mut buffer = vec![0u8; size as usize];
+            if !sys::SteamAPI_ISteamHTTP_GetHTTPResponseBodyData(self.http, self.handle, buffer.as_mut_ptr(), size) {
+                return Vec::new();
+            }
+            buffer
+        }
+    }
+}
+
+impl Drop for HTTPResponse {
+    fn drop(&mut self) {
+        unsafe {
+            sys::SteamAPI_ISteamHTTP_ReleaseHTTPRequest(self.http, self.handle);
+        }
+    }
+}
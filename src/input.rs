@@ -0,0 +1,345 @@
+use super::*;
+
+/// Access to the steam input interface
+pub struct Input<Manager> {
+    pub(crate) input: *mut sys::ISteamInput,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+/// A handle to a controller connected through steam input
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InputHandle(pub(crate) sys::InputHandle_t);
+
+impl InputHandle {
+    /// A special handle that can be used to send commands
+    /// to all connected controllers at once.
+    pub const ALL_CONTROLLERS: InputHandle = InputHandle(!0);
+
+    /// Creates an `InputHandle` from a raw 64 bit value.
+    pub fn from_raw(handle: u64) -> InputHandle {
+        InputHandle(handle)
+    }
+
+    /// Returns the raw 64 bit value of the handle
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A handle to an action set or action set layer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ActionSetHandle(pub(crate) sys::InputActionSetHandle_t);
+
+impl ActionSetHandle {
+    /// Creates an `ActionSetHandle` from a raw 64 bit value.
+    pub fn from_raw(handle: u64) -> ActionSetHandle {
+        ActionSetHandle(handle)
+    }
+
+    /// Returns the raw 64 bit value of the handle
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A touch pad on a steam controller
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ControllerPad {
+    Left,
+    Right,
+}
+
+impl Into<sys::ESteamControllerPad> for ControllerPad {
+    fn into(self) -> sys::ESteamControllerPad {
+        match self {
+            ControllerPad::Left => sys::ESteamControllerPad::k_ESteamControllerPad_Left,
+            ControllerPad::Right => sys::ESteamControllerPad::k_ESteamControllerPad_Right,
+        }
+    }
+}
+
+/// Motion sensor data from a controller
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MotionData {
+    /// The sensor-fused absolute orientation of the controller
+    /// as a quaternion in `[x, y, z, w]` order
+    pub rotation_quaternion: [f32; 4],
+    /// The positional acceleration of the controller along
+    /// each axis
+    pub acceleration: [f32; 3],
+    /// The angular velocity of the controller around each axis
+    pub angular_velocity: [f32; 3],
+}
+
+/// The type of a controller connected through steam input
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InputType {
+    Unknown,
+    SteamController,
+    XBox360Controller,
+    XBoxOneController,
+    GenericGamepad,
+    PS4Controller,
+    AppleMFiController,
+    AndroidController,
+    SwitchJoyConPair,
+    SwitchJoyConSingle,
+    SwitchProController,
+    MobileTouch,
+    PS3Controller,
+}
+
+impl From<sys::ESteamInputType> for InputType {
+    fn from(ty: sys::ESteamInputType) -> InputType {
+        match ty {
+            sys::ESteamInputType::k_ESteamInputType_SteamController => InputType::SteamController,
+            sys::ESteamInputType::k_ESteamInputType_XBox360Controller => InputType::XBox360Controller,
+            sys::ESteamInputType::k_ESteamInputType_XBoxOneController => InputType::XBoxOneController,
+            sys::ESteamInputType::k_ESteamInputType_GenericGamepad => InputType::GenericGamepad,
+            sys::ESteamInputType::k_ESteamInputType_PS4Controller => InputType::PS4Controller,
+            sys::ESteamInputType::k_ESteamInputType_AppleMFiController => InputType::AppleMFiController,
+            sys::ESteamInputType::k_ESteamInputType_AndroidController => InputType::AndroidController,
+            sys::ESteamInputType::k_ESteamInputType_SwitchJoyConPair => InputType::SwitchJoyConPair,
+            sys::ESteamInputType::k_ESteamInputType_SwitchJoyConSingle => InputType::SwitchJoyConSingle,
+            sys::ESteamInputType::k_ESteamInputType_SwitchProController => InputType::SwitchProController,
+            sys::ESteamInputType::k_ESteamInputType_MobileTouch => InputType::MobileTouch,
+            sys::ESteamInputType::k_ESteamInputType_PS3Controller => InputType::PS3Controller,
+            _ => InputType::Unknown,
+        }
+    }
+}
+
+impl <Manager> Input<Manager> {
+    /// Returns the raw `ISteamInput` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamInput {
+        self.input
+    }
+
+    /// Initializes the steam input interface.
+    ///
+    /// This must be called before any other input method.
+    pub fn init(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamInput_Init(self.input)
+        }
+    }
+
+    /// Shuts down the steam input interface.
+    pub fn shutdown(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_Shutdown(self.input);
+        }
+    }
+
+    /// Synchronizes the controller state with steam.
+    ///
+    /// This should be called once per a frame before reading
+    /// any input state.
+    pub fn run_frame(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_RunFrame(self.input);
+        }
+    }
+
+    /// Returns the handles of all currently connected controllers.
+    ///
+    /// Comparing the result between frames can be used to detect
+    /// controllers being connected or disconnected.
+    pub fn connected_controllers(&self) -> Vec<InputHandle> {
+        unsafe {
+            let mut handles = vec![0; sys::STEAM_INPUT_MAX_COUNT as usize];
+            let count = sys::SteamAPI_ISteamInput_GetConnectedControllers(self.input, handles.as_mut_ptr());
+            handles.truncate(count as usize);
+            handles.into_iter()
+                .map(InputHandle)
+                .collect()
+        }
+    }
+
+    /// Returns the type of the controller with the given handle.
+    ///
+    /// Useful for picking the correct set of button glyphs to display.
+    pub fn input_type(&self, controller: InputHandle) -> InputType {
+        unsafe {
+            sys::SteamAPI_ISteamInput_GetInputTypeForHandle(self.input, controller.0).into()
+        }
+    }
+
+    /// Returns the handle of the controller emulating the xinput
+    /// gamepad with the given index, if any.
+    pub fn controller_for_gamepad_index(&self, index: i32) -> Option<InputHandle> {
+        unsafe {
+            let handle = sys::SteamAPI_ISteamInput_GetControllerForGamepadIndex(self.input, index);
+            if handle == 0 {
+                None
+            } else {
+                Some(InputHandle(handle))
+            }
+        }
+    }
+
+    /// Looks up the handle for an action set or action set layer
+    /// by the name given in the game's action manifest.
+    ///
+    /// Returns `None` if no action set with the name exists.
+    pub fn action_set_handle(&self, name: &str) -> Option<ActionSetHandle> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            let handle = sys::SteamAPI_ISteamInput_GetActionSetHandle(self.input, name.as_ptr());
+            if handle == 0 {
+                None
+            } else {
+                Some(ActionSetHandle(handle))
+            }
+        }
+    }
+
+    /// Switches the controller to the given action set.
+    ///
+    /// This deactivates any action set layers that were active.
+    pub fn activate_action_set(&self, controller: InputHandle, action_set: ActionSetHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_ActivateActionSet(self.input, controller.0, action_set.0);
+        }
+    }
+
+    /// Returns the action set currently active on the controller
+    pub fn current_action_set(&self, controller: InputHandle) -> ActionSetHandle {
+        unsafe {
+            ActionSetHandle(sys::SteamAPI_ISteamInput_GetCurrentActionSet(self.input, controller.0))
+        }
+    }
+
+    /// Applies an action set layer on top of the current action set.
+    ///
+    /// Layers are stacked in the order they are activated, with
+    /// later layers taking priority.
+    pub fn activate_action_set_layer(&self, controller: InputHandle, layer: ActionSetHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_ActivateActionSetLayer(self.input, controller.0, layer.0);
+        }
+    }
+
+    /// Removes an action set layer from the controller
+    pub fn deactivate_action_set_layer(&self, controller: InputHandle, layer: ActionSetHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_DeactivateActionSetLayer(self.input, controller.0, layer.0);
+        }
+    }
+
+    /// Removes all action set layers from the controller
+    pub fn deactivate_all_action_set_layers(&self, controller: InputHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_DeactivateAllActionSetLayers(self.input, controller.0);
+        }
+    }
+
+    /// Returns the action set layers currently active on the
+    /// controller in the order they were activated.
+    pub fn active_action_set_layers(&self, controller: InputHandle) -> Vec<ActionSetHandle> {
+        unsafe {
+            let mut handles = vec![0; sys::STEAM_INPUT_MAX_ACTIVE_LAYERS as usize];
+            let count = sys::SteamAPI_ISteamInput_GetActiveActionSetLayers(self.input, controller.0, handles.as_mut_ptr());
+            handles.truncate(count as usize);
+            handles.into_iter()
+                .map(ActionSetHandle)
+                .collect()
+        }
+    }
+
+    /// Returns the current motion data (gyro and accelerometer)
+    /// of the controller.
+    ///
+    /// Controllers without motion sensors return zeroed data.
+    pub fn motion_data(&self, controller: InputHandle) -> MotionData {
+        unsafe {
+            let data = sys::SteamAPI_ISteamInput_GetMotionData(self.input, controller.0);
+            MotionData {
+                rotation_quaternion: [data.rotQuatX, data.rotQuatY, data.rotQuatZ, data.rotQuatW],
+                acceleration: [data.posAccelX, data.posAccelY, data.posAccelZ],
+                angular_velocity: [data.rotVelX, data.rotVelY, data.rotVelZ],
+            }
+        }
+    }
+
+    /// Opens the steam input configurator overlay for the controller
+    /// so the user can edit their bindings.
+    ///
+    /// Returns false if the overlay is disabled or unavailable.
+    pub fn show_binding_panel(&self, controller: InputHandle) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamInput_ShowBindingPanel(self.input, controller.0)
+        }
+    }
+
+    /// Triggers a vibration event on controllers that support it.
+    ///
+    /// The speeds control the left and right rumble motors. Passing
+    /// zero for both stops any current vibration.
+    pub fn trigger_vibration(&self, controller: InputHandle, left_speed: u16, right_speed: u16) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_TriggerVibration(self.input, controller.0, left_speed, right_speed);
+        }
+    }
+
+    /// Triggers a single haptic pulse on a steam controller pad.
+    ///
+    /// This is a legacy api that only works with steam controllers.
+    pub fn trigger_haptic_pulse(&self, controller: InputHandle, pad: ControllerPad, duration_micro_sec: u16) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_TriggerHapticPulse(self.input, controller.0, pad.into(), duration_micro_sec);
+        }
+    }
+
+    /// Triggers a repeating haptic pulse on a steam controller pad.
+    ///
+    /// The pad will pulse on for `on_micro_sec`, off for `off_micro_sec`,
+    /// `repeat` times.
+    ///
+    /// This is a legacy api that only works with steam controllers.
+    pub fn trigger_repeated_haptic_pulse(
+        &self, controller: InputHandle, pad: ControllerPad,
+        on_micro_sec: u16, off_micro_sec: u16, repeat: u16,
+    ) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_TriggerRepeatedHapticPulse(
+                self.input, controller.0, pad.into(),
+                on_micro_sec, off_micro_sec, repeat, 0
+            );
+        }
+    }
+
+    /// Sets the color of the controller's LED (e.g. the DualShock 4 light bar)
+    pub fn set_led_color(&self, controller: InputHandle, r: u8, g: u8, b: u8) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_SetLEDColor(
+                self.input, controller.0, r, g, b,
+                sys::ESteamInputLEDFlag::k_ESteamInputLEDFlag_SetColor as _
+            );
+        }
+    }
+
+    /// Restores the controller's LED to the color the user
+    /// has configured.
+    pub fn reset_led_color(&self, controller: InputHandle) {
+        unsafe {
+            sys::SteamAPI_ISteamInput_SetLEDColor(
+                self.input, controller.0, 0, 0, 0,
+                sys::ESteamInputLEDFlag::k_ESteamInputLEDFlag_RestoreUserDefault as _
+            );
+        }
+    }
+}
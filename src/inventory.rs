@@ -0,0 +1,659 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 4700;
+
+/// Access to the steam inventory interface
+pub struct Inventory<Manager> {
+    pub(crate) inventory: *mut sys::ISteamInventory,
+    pub(crate) inner: Arc<Inner<Manager>>,
+}
+
+/// The id of a specific item instance in a user's inventory
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemInstanceId(pub u64);
+
+/// The id of an item definition as configured for the app
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemDefId(pub i32);
+
+/// A handle to an inventory result, as passed to `InventoryResultReady`
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct InventoryResultHandle(pub(crate) sys::SteamInventoryResult_t);
+
+bitflags! {
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[repr(C)]
+    pub struct ItemFlags: u16 {
+        /// The item is account-bound and cannot be traded
+        const NO_TRADE = 0x0001;
+        /// The item has been destroyed, traded away, expired
+        /// or otherwise invalidated
+        const REMOVED  = 0x0100;
+        /// The item quantity has been decreased by one
+        const CONSUMED = 0x0200;
+    }
+}
+
+/// A stack of items in a user's inventory
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemInstance {
+    /// The globally unique id of the item
+    pub item_id: ItemInstanceId,
+    /// The definition that describes the item
+    pub definition: ItemDefId,
+    /// The number of items in the stack
+    pub quantity: u16,
+    /// Flags describing the state of the item
+    pub flags: ItemFlags,
+}
+
+/// The price of an item definition in the user's local currency
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemPrice {
+    /// The item definition the price is for
+    pub definition: ItemDefId,
+    /// The current price, including any discounts
+    pub current_price: u64,
+    /// The price before any discounts
+    pub base_price: u64,
+}
+
+/// Information about a purchase started with `start_purchase`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PurchaseStarted {
+    /// The id of the order
+    pub order_id: u64,
+    /// The id of the transaction
+    pub transaction_id: u64,
+}
+
+impl <Manager> Inventory<Manager> {
+    /// Returns the raw `ISteamInventory` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamInventory {
+        self.inventory
+    }
+
+    /// Requests the full inventory of the current user.
+    ///
+    /// The returned result will be filled once an
+    /// `InventoryResultReady` callback for its handle is received.
+    pub fn get_all_items(&self) -> SResult<InventoryResult<Manager>> {
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_GetAllItems(self.inventory, handle))
+        }
+    }
+
+    /// Requests the state of specific items in the current user's
+    /// inventory.
+    ///
+    /// The returned result will be filled once an
+    /// `InventoryResultReady` callback for its handle is received.
+    pub fn get_items_by_id(&self, items: &[ItemInstanceId]) -> SResult<InventoryResult<Manager>> {
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_GetItemsByID(
+                self.inventory, handle,
+                items.as_ptr() as *const _, items.len() as _
+            ))
+        }
+    }
+
+    /// Grants the items in `generate` in exchange for consuming the
+    /// items in `destroy`.
+    ///
+    /// The exchange must match a recipe set up in the item
+    /// definitions of the generated item.
+    pub fn exchange_items(&self, generate: &[(ItemDefId, u32)], destroy: &[(ItemInstanceId, u32)]) -> SResult<InventoryResult<Manager>> {
+        let (generate, generate_quantity): (Vec<_>, Vec<_>) = generate.iter()
+            .map(|&(def, quantity)| (def.0, quantity))
+            .unzip();
+        let (destroy, destroy_quantity): (Vec<_>, Vec<_>) = destroy.iter()
+            .map(|&(item, quantity)| (item.0, quantity))
+            .unzip();
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_ExchangeItems(
+                self.inventory, handle,
+                generate.as_ptr(), generate_quantity.as_ptr(), generate.len() as _,
+                destroy.as_ptr(), destroy_quantity.as_ptr(), destroy.len() as _
+            ))
+        }
+    }
+
+    /// Grants the given items to the current user.
+    ///
+    /// This is only usable by developers and publishers of the app
+    /// and is intended for testing.
+    pub fn generate_items(&self, items: &[(ItemDefId, u32)]) -> SResult<InventoryResult<Manager>> {
+        let (defs, quantities): (Vec<_>, Vec<_>) = items.iter()
+            .map(|&(def, quantity)| (def.0, quantity))
+            .unzip();
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_GenerateItems(
+                self.inventory, handle,
+                defs.as_ptr(), quantities.as_ptr(), defs.len() as _
+            ))
+        }
+    }
+
+    /// Consumes `quantity` items from the given stack, removing
+    /// the stack if it is emptied.
+    pub fn consume_item(&self, item: ItemInstanceId, quantity: u32) -> SResult<InventoryResult<Manager>> {
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_ConsumeItem(
+                self.inventory, handle, item.0, quantity
+            ))
+        }
+    }
+
+    /// Moves `quantity` items from one stack to another.
+    ///
+    /// If `destination` is `None` the items are split off into a new
+    /// stack.
+    pub fn transfer_item_quantity(&self, source: ItemInstanceId, quantity: u32, destination: Option<ItemInstanceId>) -> SResult<InventoryResult<Manager>> {
+        let destination = destination.map_or(!0, |v| v.0);
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_TransferItemQuantity(
+                self.inventory, handle, source.0, quantity, destination
+            ))
+        }
+    }
+
+    /// Grants a specific promo item to the current user if they
+    /// are eligible for it.
+    pub fn add_promo_item(&self, item: ItemDefId) -> SResult<InventoryResult<Manager>> {
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_AddPromoItem(
+                self.inventory, handle, item.0
+            ))
+        }
+    }
+
+    /// Grants the given promo items to the current user if they
+    /// are eligible for them.
+    pub fn add_promo_items(&self, items: &[ItemDefId]) -> SResult<InventoryResult<Manager>> {
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_AddPromoItems(
+                self.inventory, handle,
+                items.as_ptr() as *const _, items.len() as _
+            ))
+        }
+    }
+
+    /// Grants all promo items the current user is eligible for.
+    pub fn grant_promo_items(&self) -> SResult<InventoryResult<Manager>> {
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_GrantPromoItems(self.inventory, handle))
+        }
+    }
+
+    /// Grants an item drop from the given playtime drop list if
+    /// the user is eligible for one.
+    ///
+    /// This should be called at natural breaks in gameplay (e.g. the
+    /// end of a match). The result will contain the dropped item, if
+    /// any, once an `InventoryResultReady` callback for its handle is
+    /// received.
+    pub fn trigger_item_drop(&self, drop_list: ItemDefId) -> SResult<InventoryResult<Manager>> {
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_TriggerItemDrop(
+                self.inventory, handle, drop_list.0
+            ))
+        }
+    }
+
+    /// Requests the prices of all item definitions that can be
+    /// purchased in the user's local currency.
+    ///
+    /// The callback receives the currency code of the prices.
+    pub fn request_prices<F>(&self, mut cb: F)
+        where F: FnMut(SResult<String>) + 'static + Send
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamInventory_RequestPrices(self.inventory);
+            register_call_result::<sys::SteamInventoryRequestPricesResult_t, _, _>(
+                &self.inner, api_call, CALLBACK_BASE_ID + 5,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else if v.m_result != sys::EResult::k_EResultOK {
+                        Err(v.m_result.into())
+                    } else {
+                        let currency = CStr::from_ptr(v.m_rgchCurrency.as_ptr());
+                        Ok(currency.to_string_lossy().into_owned())
+                    })
+                }
+            );
+        }
+    }
+
+    /// Returns the prices of all purchasable item definitions.
+    ///
+    /// Requires a successful `request_prices` call.
+    pub fn items_with_prices(&self) -> Vec<ItemPrice> {
+        unsafe {
+            let count = sys::SteamAPI_ISteamInventory_GetNumItemsWithPrices(self.inventory);
+            let mut defs = vec![0; count as usize];
+            let mut current_prices = vec![0; count as usize];
+            let mut base_prices = vec![0; count as usize];
+            if !sys::SteamAPI_ISteamInventory_GetItemsWithPrices(
+                self.inventory,
+                defs.as_mut_ptr(), current_prices.as_mut_ptr(), base_prices.as_mut_ptr(),
+                count
+            ) {
+                return Vec::new();
+            }
+            defs.into_iter()
+                .zip(current_prices)
+                .zip(base_prices)
+                .map(|((def, current_price), base_price)| ItemPrice {
+                    definition: ItemDefId(def),
+                    current_price,
+                    base_price,
+                })
+                .collect()
+        }
+    }
+
+    /// Returns the price of a single item definition.
+    ///
+    /// Requires a successful `request_prices` call.
+    pub fn item_price(&self, definition: ItemDefId) -> Option<ItemPrice> {
+        unsafe {
+            let mut current_price = 0;
+            let mut base_price = 0;
+            if sys::SteamAPI_ISteamInventory_GetItemPrice(self.inventory, definition.0, &mut current_price, &mut base_price) {
+                Some(ItemPrice {
+                    definition,
+                    current_price,
+                    base_price,
+                })
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Starts the purchase of the given items through the steam
+    /// overlay.
+    ///
+    /// An `InventoryResultReady` callback will follow once the
+    /// purchase has completed.
+    pub fn start_purchase<F>(&self, items: &[(ItemDefId, u32)], mut cb: F)
+        where F: FnMut(SResult<PurchaseStarted>) + 'static + Send
+    {
+        let (defs, quantities): (Vec<_>, Vec<_>) = items.iter()
+            .map(|&(def, quantity)| (def.0, quantity))
+            .unzip();
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamInventory_StartPurchase(
+                self.inventory, defs.as_ptr(), quantities.as_ptr(), defs.len() as _
+            );
+            register_call_result::<sys::SteamInventoryStartPurchaseResult_t, _, _>(
+                &self.inner, api_call, CALLBACK_BASE_ID + 4,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else if v.m_result != sys::EResult::k_EResultOK {
+                        Err(v.m_result.into())
+                    } else {
+                        Ok(PurchaseStarted {
+                            order_id: v.m_ulOrderID,
+                            transaction_id: v.m_ulTransID,
+                        })
+                    })
+                }
+            );
+        }
+    }
+
+    /// Recreates a result from data produced by `InventoryResult::serialize`.
+    ///
+    /// This is used by game servers (or clients) to verify items
+    /// another user claims to own. The result will be ready once an
+    /// `InventoryResultReady` callback for its handle is received,
+    /// after which its status will be `Err(SteamError::Expired)` if the
+    /// data is too old or `Err(SteamError::InvalidParameter)` if it has
+    /// been tampered with. `InventoryResult::check_steam_id` should be
+    /// used to confirm who the result belongs to.
+    pub fn deserialize_result(&self, data: &[u8]) -> SResult<InventoryResult<Manager>> {
+        unsafe {
+            self.request(|handle| sys::SteamAPI_ISteamInventory_DeserializeResult(
+                self.inventory, handle,
+                data.as_ptr() as *const _, data.len() as _,
+                false
+            ))
+        }
+    }
+
+    /// Triggers an asynchronous load and refresh of the item definitions.
+    ///
+    /// An `InventoryDefinitionUpdate` callback is fired once the
+    /// definitions are available.
+    pub fn load_item_definitions(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamInventory_LoadItemDefinitions(self.inventory)
+        }
+    }
+
+    /// Returns the ids of all loaded item definitions.
+    ///
+    /// Returns `None` if the definitions haven't been loaded yet.
+    pub fn item_definition_ids(&self) -> Option<Vec<ItemDefId>> {
+        unsafe {
+            let mut count = 0;
+            if !sys::SteamAPI_ISteamInventory_GetItemDefinitionIDs(self.inventory, std::ptr::null_mut(), &mut count) {
+                return None;
+            }
+            let mut ids = vec![0; count as usize];
+            if !sys::SteamAPI_ISteamInventory_GetItemDefinitionIDs(self.inventory, ids.as_mut_ptr(), &mut count) {
+                return None;
+            }
+            ids.truncate(count as usize);
+            Some(ids.into_iter().map(ItemDefId).collect())
+        }
+    }
+
+    /// Returns the value of a property of an item definition.
+    ///
+    /// Returns `None` if the definition or property doesn't exist.
+    pub fn item_definition_property(&self, definition: ItemDefId, name: &str) -> Option<String> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            self.get_definition_property(definition, name.as_ptr())
+        }
+    }
+
+    /// Returns the names of all properties set on an item definition.
+    pub fn item_definition_property_names(&self, definition: ItemDefId) -> Vec<String> {
+        unsafe {
+            self.get_definition_property(definition, std::ptr::null())
+                .map(|names| names.split(',')
+                    .filter(|v| !v.is_empty())
+                    .map(|v| v.to_owned())
+                    .collect())
+                .unwrap_or_default()
+        }
+    }
+
+    /// Returns all properties set on an item definition.
+    pub fn item_definition_properties(&self, definition: ItemDefId) -> HashMap<String, String> {
+        self.item_definition_property_names(definition)
+            .into_iter()
+            .filter_map(|name| {
+                let value = self.item_definition_property(definition, &name)?;
+                Some((name, value))
+            })
+            .collect()
+    }
+
+    /// Returns the localized name of an item definition
+    pub fn item_definition_name(&self, definition: ItemDefId) -> Option<String> {
+        self.item_definition_property(definition, "name")
+    }
+
+    /// Returns the localized description of an item definition
+    pub fn item_definition_description(&self, definition: ItemDefId) -> Option<String> {
+        self.item_definition_property(definition, "description")
+    }
+
+    /// Returns the url of the small icon of an item definition
+    pub fn item_definition_icon_url(&self, definition: ItemDefId) -> Option<String> {
+        self.item_definition_property(definition, "icon_url")
+    }
+
+    unsafe fn get_definition_property(&self, definition: ItemDefId, name: *const libc::c_char) -> Option<String> {
+        let mut size = 0;
+        if !sys::SteamAPI_ISteamInventory_GetItemDefinitionProperty(
+            self.inventory, definition.0, name, std::ptr::null_mut(), &mut size
+        ) {
+            return None;
+        }
+        let mut buffer = vec![0; size as usize];
+        if !sys::SteamAPI_ISteamInventory_GetItemDefinitionProperty(
+            self.inventory, definition.0, name, buffer.as_mut_ptr(), &mut size
+        ) {
+            return None;
+        }
+        if buffer.is_empty() {
+            return Some(String::new());
+        }
+        let value = CStr::from_ptr(buffer.as_ptr());
+        Some(value.to_string_lossy().into_owned())
+    }
+
+    unsafe fn request<F>(&self, f: F) -> SResult<InventoryResult<Manager>>
+        where F: FnOnce(*mut sys::SteamInventoryResult_t) -> bool
+    {
+        let mut handle = sys::k_SteamInventoryResultInvalid;
+        if f(&mut handle) {
+            Ok(InventoryResult {
+                inventory: self.inventory,
+                _inner: self.inner.clone(),
+                handle,
+            })
+        } else {
+            Err(SteamError::Generic)
+        }
+    }
+}
+
+/// The result of an inventory request.
+///
+/// The result is destroyed when this is dropped.
+pub struct InventoryResult<Manager> {
+    inventory: *mut sys::ISteamInventory,
+    _inner: Arc<Inner<Manager>>,
+    handle: sys::SteamInventoryResult_t,
+}
+
+unsafe impl <Manager> Send for InventoryResult<Manager> {}
+
+impl <Manager> Drop for InventoryResult<Manager> {
+    fn drop(&mut self) {
+        unsafe {
+            sys::SteamAPI_ISteamInventory_DestroyResult(self.inventory, self.handle);
+        }
+    }
+}
+
+impl <Manager> InventoryResult<Manager> {
+    /// Returns the handle of this result.
+    ///
+    /// Can be compared against the handle of an `InventoryResultReady`
+    /// callback.
+    pub fn handle(&self) -> InventoryResultHandle {
+        InventoryResultHandle(self.handle)
+    }
+
+    /// Returns the status of this result.
+    ///
+    /// Returns `Err(SteamError::Pending)` if the result isn't ready yet.
+    pub fn status(&self) -> SResult<()> {
+        unsafe {
+            match sys::SteamAPI_ISteamInventory_GetResultStatus(self.inventory, self.handle) {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            }
+        }
+    }
+
+    /// Returns the items contained in this result.
+    ///
+    /// Returns `None` if the result isn't ready or failed.
+    pub fn items(&self) -> Option<Vec<ItemInstance>> {
+        unsafe {
+            let mut count = 0;
+            if !sys::SteamAPI_ISteamInventory_GetResultItems(self.inventory, self.handle, std::ptr::null_mut(), &mut count) {
+                return None;
+            }
+            let mut items: Vec<sys::SteamItemDetails_t> = Vec::with_capacity(count as usize);
+            if !sys::SteamAPI_ISteamInventory_GetResultItems(self.inventory, self.handle, items.as_mut_ptr(), &mut count) {
+                return None;
+            }
+            items.set_len(count as usize);
+            Some(items.into_iter()
+                .map(|item| ItemInstance {
+                    item_id: ItemInstanceId(item.m_itemId),
+                    definition: ItemDefId(item.m_iDefinition),
+                    quantity: item.m_unQuantity,
+                    flags: ItemFlags::from_bits_truncate(item.m_unFlags),
+                })
+                .collect())
+        }
+    }
+
+    /// Returns the value of a property of the item at the given
+    /// index in `items`.
+    ///
+    /// This includes the dynamic properties of the item instance as
+    /// well as `"tags"` and `"dynamic_props"` which contain all of
+    /// them. Returns `None` if the item or property doesn't exist.
+    pub fn item_property(&self, index: u32, name: &str) -> Option<String> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            self.get_item_property(index, name.as_ptr())
+        }
+    }
+
+    /// Returns the names of all properties set on the item at the
+    /// given index in `items`.
+    pub fn item_property_names(&self, index: u32) -> Vec<String> {
+        unsafe {
+            self.get_item_property(index, std::ptr::null())
+                .map(|names| names.split(',')
+                    .filter(|v| !v.is_empty())
+                    .map(|v| v.to_owned())
+                    .collect())
+                .unwrap_or_default()
+        }
+    }
+
+    unsafe fn get_item_property(&self, index: u32, name: *const libc::c_char) -> Option<String> {
+        let mut size = 0;
+        if !sys::SteamAPI_ISteamInventory_GetResultItemProperty(
+            self.inventory, self.handle, index, name, std::ptr::null_mut(), &mut size
+        ) {
+            return None;
+        }
+        let mut buffer = vec![0; size as usize];
+        if !sys::SteamAPI_ISteamInventory_GetResultItemProperty(
+            self.inventory, self.handle, index, name, buffer.as_mut_ptr(), &mut size
+        ) {
+            return None;
+        }
+        if buffer.is_empty() {
+            return Some(String::new());
+        }
+        let value = CStr::from_ptr(buffer.as_ptr());
+        Some(value.to_string_lossy().into_owned())
+    }
+
+    /// Serializes the result into a signed blob that can be sent to
+    /// another party and checked with `Inventory::deserialize_result`.
+    ///
+    /// The serialized data expires after about an hour. Returns `None`
+    /// if the result isn't ready or failed.
+    pub fn serialize(&self) -> Option<Vec<u8>> {
+        unsafe {
+            let mut size = 0;
+            if !sys::SteamAPI_ISteamInventory_SerializeResult(self.inventory, self.handle, std::ptr::null_mut(), &mut size) {
+                return None;
+            }
+            let mut data = vec![0u8; size as usize];
+            if !sys::SteamAPI_ISteamInventory_SerializeResult(self.inventory, self.handle, data.as_mut_ptr() as *mut _, &mut size) {
+                return None;
+            }
+            data.truncate(size as usize);
+            Some(data)
+        }
+    }
+
+    /// Returns the server time at which the result was generated
+    /// in unix epoch seconds format.
+    pub fn timestamp(&self) -> u32 {
+        unsafe {
+            sys::SteamAPI_ISteamInventory_GetResultTimestamp(self.inventory, self.handle)
+        }
+    }
+
+    /// Returns whether the result belongs to the given steam id.
+    pub fn check_steam_id(&self, steam_id: SteamId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamInventory_CheckResultSteamID(self.inventory, self.handle, steam_id.0)
+        }
+    }
+}
+
+/// Called when an inventory result has finished or failed
+#[derive(Clone, Debug)]
+pub struct InventoryResultReady {
+    /// The handle of the result that is ready
+    pub handle: InventoryResultHandle,
+    /// Whether the request succeeded
+    pub result: SResult<()>,
+}
+
+unsafe impl Callback for InventoryResultReady {
+    const ID: i32 = CALLBACK_BASE_ID;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamInventoryResultReady_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamInventoryResultReady_t);
+        InventoryResultReady {
+            handle: InventoryResultHandle(val.m_handle),
+            result: match val.m_result {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+        }
+    }
+}
+
+/// Called when a result containing the user's full inventory is ready.
+///
+/// This is sent in addition to `InventoryResultReady` and may also be
+/// triggered by steam itself.
+#[derive(Clone, Debug)]
+pub struct InventoryFullUpdate {
+    /// The handle of the result holding the full inventory
+    pub handle: InventoryResultHandle,
+}
+
+unsafe impl Callback for InventoryFullUpdate {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamInventoryFullUpdate_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamInventoryFullUpdate_t);
+        InventoryFullUpdate {
+            handle: InventoryResultHandle(val.m_handle),
+        }
+    }
+}
+
+/// Called when the item definitions have been loaded or updated.
+///
+/// Item definitions should be re-read when this is received.
+#[derive(Clone, Debug)]
+pub struct InventoryDefinitionUpdate;
+
+unsafe impl Callback for InventoryDefinitionUpdate {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamInventoryDefinitionUpdate_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        InventoryDefinitionUpdate
+    }
+}
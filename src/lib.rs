@@ -1,6 +1,14 @@
 
 use libc;
-extern crate steamworks_sys as sys;
+/// The raw sdk bindings, for use with the `as_raw` methods
+/// on each interface.
+///
+/// The raw interface pointers allow calling parts of the sdk that
+/// aren't wrapped yet. The crate uses manual callback dispatch, so
+/// `SteamAPI_RunCallbacks` must not be called. Callbacks triggered by
+/// raw calls are delivered by `SingleClient::run_callbacks` to the
+/// handlers registered with `Client::register_callback`.
+pub extern crate steamworks_sys as sys;
 #[macro_use]
 extern crate failure;
 #[macro_use]
@@ -10,6 +18,17 @@ extern crate lazy_static;
 
 mod error;
 pub use crate::error::*;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "metrics")]
+pub use crate::metrics::*;
+#[cfg(feature = "async")]
+pub mod async_runtime;
+#[cfg(feature = "controller")]
+pub mod controller;
+#[cfg(feature = "voice_chat")]
+pub mod voice_chat;
+pub mod workshop;
 
 mod callback;
 pub use crate::callback::*;
@@ -33,6 +52,40 @@ mod remote_storage;
 pub use crate::remote_storage::*;
 mod ugc;
 pub use crate::ugc::*;
+mod input;
+pub use crate::input::*;
+mod inventory;
+pub use crate::inventory::*;
+mod screenshots;
+pub use crate::screenshots::*;
+mod music;
+pub use crate::music::*;
+mod music_remote;
+pub use crate::music_remote::*;
+mod html_surface;
+pub use crate::html_surface::*;
+mod video;
+pub use crate::video::*;
+mod parental;
+pub use crate::parental::*;
+mod remote_play;
+pub use crate::remote_play::*;
+mod parties;
+pub use crate::parties::*;
+mod game_search;
+pub use crate::game_search::*;
+mod networking_sockets;
+pub use crate::networking_sockets::*;
+mod networking_utils;
+pub use crate::networking_utils::*;
+mod networking_types;
+pub use crate::networking_types::*;
+mod peer_connection;
+pub use crate::peer_connection::*;
+mod http;
+pub use crate::http::*;
+mod lobby_session;
+pub use crate::lobby_session::*;
 
 use std::sync::{Arc, Mutex};
 use std::ffi::{CString, CStr};
@@ -57,6 +110,11 @@ pub type SResult<T> = Result<T, SteamError>;
 ///
 /// This provides access to all of the steamworks api that
 /// clients can use.
+///
+/// `Client` is `Send` and `Sync` and can be cloned freely to use the
+/// api from any thread. Work that has to happen on the thread pumping
+/// callbacks is only available through the `SingleClient` returned
+/// alongside it by `Client::init`.
 pub struct Client<Manager = ClientManager> {
     inner: Arc<Inner<Manager>>,
 }
@@ -71,18 +129,42 @@ impl <Manager> Clone for Client<Manager> {
 
 /// Allows access parts of the steam api that can only be called
 /// on a single thread at any given time.
+///
+/// There is only ever one `SingleClient` per client or server. It can
+/// be moved to the thread that should pump callbacks but it can't be
+/// shared between threads, which is enforced at compile time:
+///
+/// ```compile_fail
+/// # use steamworks::*;
+/// fn assert_sync<T: Sync>(_: &T) {}
+/// let (_client, single) = Client::init().unwrap();
+/// assert_sync(&single);
+/// ```
 pub struct SingleClient<Manager = ClientManager> {
     inner: Arc<Inner<Manager>>,
     _not_sync: PhantomData<*mut ()>,
 }
 
+/// An alias for `SingleClient` that describes its role of
+/// pumping callbacks on a single thread
+pub type CallbackPump<Manager = ClientManager> = SingleClient<Manager>;
+
 struct Inner<Manager> {
     _manager: Manager,
     callbacks: Mutex<Callbacks>,
+    #[cfg(feature = "metrics")]
+    stats: Mutex<CallbackStats>,
 }
 
+// Callbacks are reference counted so that `run_callbacks` only has to hold
+// the registry lock while looking the callbacks up, not while running them.
+// This keeps registering and removing callbacks from other threads from
+// waiting on a long running callback, and lets callbacks register other
+// callbacks without deadlocking.
+type SharedCallback = Arc<Mutex<dyn FnMut(*mut libc::c_void) + Send + 'static>>;
+
 struct Callbacks {
-    callbacks: HashMap<i32, Box<dyn FnMut(*mut libc::c_void) + Send + 'static>>,
+    callbacks: HashMap<i32, Vec<SharedCallback>>,
     call_results: HashMap<sys::SteamAPICall_t, Box<dyn FnMut(*mut libc::c_void, bool) + Send + 'static>>,
 }
 
@@ -103,6 +185,18 @@ pub fn restart_app_if_necessary(app_id: AppId) -> bool {
     }
 }
 
+/// Sets whether steam catches exceptions thrown from callbacks
+/// it dispatches internally.
+///
+/// Defaults to true. Engines that want crashes to reach their own
+/// handler can turn this off. This must be called before
+/// `Client::init` or `Server::init`.
+pub fn set_try_catch_callbacks(try_catch: bool) {
+    unsafe {
+        sys::SteamAPI_SetTryCatchCallbacks(try_catch);
+    }
+}
+
 fn static_assert_send<T: Send>() {}
 fn static_assert_sync<T>() where T: Sync {}
 
@@ -139,6 +233,8 @@ impl Client<ClientManager> {
                     callbacks: HashMap::new(),
                     call_results: HashMap::new(),
                 }),
+                #[cfg(feature = "metrics")]
+                stats: Mutex::new(CallbackStats::default()),
             });
             Ok((Client {
                 inner: client.clone(),
@@ -158,12 +254,13 @@ impl <M> SingleClient<M> where M: Manager {
     /// This should be called frequently (e.g. once per a frame)
     /// in order to reduce the latency between recieving events.
     pub fn run_callbacks(&self) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("run_callbacks").entered();
         unsafe {
             let pipe = M::get_pipe();
             sys::SteamAPI_ManualDispatch_RunFrame(pipe);
             let mut callback = std::mem::zeroed();
             while sys::SteamAPI_ManualDispatch_GetNextCallback(pipe, &mut callback) {
-                let mut callbacks = self.inner.callbacks.lock().unwrap();
                 if callback.m_iCallback == sys::SteamAPICallCompleted_t_k_iCallback as i32 {
                     let apicall = &mut *(callback.m_pubParam as *mut _ as *mut sys::SteamAPICallCompleted_t);
                     let mut apicall_result = vec![0; apicall.m_cubParam as usize];
@@ -173,13 +270,43 @@ impl <M> SingleClient<M> where M: Manager {
                         apicall_result.as_mut_ptr() as *mut _, apicall.m_cubParam as _,
                         apicall.m_iCallback, &mut failed
                     ) {
-                        if let Some(mut cb) = callbacks.call_results.remove(&apicall.m_hAsyncCall) {
+                        let cb = self.inner.callbacks.lock().unwrap()
+                            .call_results.remove(&apicall.m_hAsyncCall);
+                        if let Some(mut cb) = cb {
+                            #[cfg(feature = "tracing")]
+                            let _span = {
+                                let id = apicall.m_iCallback;
+                                if failed {
+                                    tracing::warn!(id, "call result failed");
+                                } else {
+                                    tracing::debug!(id, "call result completed");
+                                }
+                                tracing::trace_span!("call_result", id, failed).entered()
+                            };
+                            #[cfg(feature = "metrics")]
+                            let start = std::time::Instant::now();
                             cb(apicall_result.as_mut_ptr() as *mut _, failed);
+                            #[cfg(feature = "metrics")]
+                            self.inner.stats.lock().unwrap().record_call_result(apicall.m_iCallback, start);
                         }
                     }
                 } else {
-                    if let Some(cb) = callbacks.callbacks.get_mut(&callback.m_iCallback) {
-                        cb(callback.m_pubParam as *mut _);
+                    let cbs = self.inner.callbacks.lock().unwrap()
+                        .callbacks.get(&callback.m_iCallback).cloned();
+                    if let Some(cbs) = cbs {
+                        #[cfg(feature = "tracing")]
+                        let _span = {
+                            let id = callback.m_iCallback;
+                            tracing::trace_span!("callback", id).entered()
+                        };
+                        #[cfg(feature = "metrics")]
+                        let start = std::time::Instant::now();
+                        for cb in cbs {
+                            let mut cb = cb.lock().unwrap();
+                            (*cb)(callback.m_pubParam as *mut _);
+                        }
+                        #[cfg(feature = "metrics")]
+                        self.inner.stats.lock().unwrap().record_callback(callback.m_iCallback, start);
                     }
                 }
                 sys::SteamAPI_ManualDispatch_FreeLastCallback(pipe);
@@ -188,12 +315,44 @@ impl <M> SingleClient<M> where M: Manager {
     }
 }
 
+impl <M> Client<M> where M: Manager {
+    /// Returns the raw steam pipe used by this client
+    ///
+    /// # Safety
+    ///
+    /// The pipe is closed when steam is shut down, which happens once
+    /// the last handle to the client is dropped. It must not be
+    /// released by hand. See `sys` for more.
+    pub unsafe fn as_raw_pipe(&self) -> sys::HSteamPipe {
+        M::get_pipe()
+    }
+}
+
 impl <Manager> Client<Manager> {
+    /// Returns a snapshot of the callback dispatch stats collected
+    /// so far.
+    ///
+    /// Only available with the `metrics` feature.
+    #[cfg(feature = "metrics")]
+    pub fn callback_stats(&self) -> CallbackStats {
+        self.inner.stats.lock().unwrap().clone()
+    }
+
+    /// Clears the callback dispatch stats collected so far.
+    ///
+    /// Only available with the `metrics` feature.
+    #[cfg(feature = "metrics")]
+    pub fn reset_callback_stats(&self) {
+        *self.inner.stats.lock().unwrap() = CallbackStats::default();
+    }
+
     /// Registers the passed function as a callback for the
     /// given type.
     ///
     /// The callback will be run on the thread that `run_callbacks`
-    /// is called when the event arrives.
+    /// is called when the event arrives. Several callbacks can be
+    /// registered for the same type, they are run in the order they
+    /// were registered.
     pub fn register_callback<C, F>(&self, f: F) -> CallbackHandle<Manager>
         where C: Callback,
               F: FnMut(C) + 'static + Send
@@ -314,6 +473,187 @@ impl <Manager> Client<Manager> {
             }
         }
     }
+
+    /// Returns an accessor to the steam input interface
+    pub fn input(&self) -> Input<Manager> {
+        unsafe {
+            let input = sys::SteamAPI_SteamInput_v001();
+            debug_assert!(!input.is_null());
+            Input {
+                input,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the legacy steam controller interface
+    #[cfg(feature = "controller")]
+    pub fn controller(&self) -> controller::Controller<Manager> {
+        unsafe {
+            let controller = sys::SteamAPI_SteamController_v007();
+            debug_assert!(!controller.is_null());
+            controller::Controller {
+                controller,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam inventory interface
+    pub fn inventory(&self) -> Inventory<Manager> {
+        unsafe {
+            let inventory = sys::SteamAPI_SteamInventory_v003();
+            debug_assert!(!inventory.is_null());
+            Inventory {
+                inventory,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam screenshots interface
+    pub fn screenshots(&self) -> Screenshots<Manager> {
+        unsafe {
+            let screenshots = sys::SteamAPI_SteamScreenshots_v003();
+            debug_assert!(!screenshots.is_null());
+            Screenshots {
+                screenshots,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam music interface
+    pub fn music(&self) -> Music<Manager> {
+        unsafe {
+            let music = sys::SteamAPI_SteamMusic_v001();
+            debug_assert!(!music.is_null());
+            Music {
+                music,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam music remote interface
+    pub fn music_remote(&self) -> MusicRemote<Manager> {
+        unsafe {
+            let music_remote = sys::SteamAPI_SteamMusicRemote_v001();
+            debug_assert!(!music_remote.is_null());
+            MusicRemote {
+                music_remote,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam html surface interface
+    pub fn html_surface(&self) -> HtmlSurface<Manager> {
+        unsafe {
+            let html = sys::SteamAPI_SteamHTMLSurface_v005();
+            debug_assert!(!html.is_null());
+            HtmlSurface {
+                html,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam video interface
+    pub fn video(&self) -> Video<Manager> {
+        unsafe {
+            let video = sys::SteamAPI_SteamVideo_v002();
+            debug_assert!(!video.is_null());
+            Video {
+                video,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam parental settings interface
+    pub fn parental_settings(&self) -> ParentalSettings<Manager> {
+        unsafe {
+            let parental = sys::SteamAPI_SteamParentalSettings_v001();
+            debug_assert!(!parental.is_null());
+            ParentalSettings {
+                parental,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam remote play interface
+    pub fn remote_play(&self) -> RemotePlay<Manager> {
+        unsafe {
+            let remote_play = sys::SteamAPI_SteamRemotePlay_v001();
+            debug_assert!(!remote_play.is_null());
+            RemotePlay {
+                remote_play,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam parties interface
+    pub fn parties(&self) -> Parties<Manager> {
+        unsafe {
+            let parties = sys::SteamAPI_SteamParties_v002();
+            debug_assert!(!parties.is_null());
+            Parties {
+                parties,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam game search interface
+    pub fn game_search(&self) -> GameSearch<Manager> {
+        unsafe {
+            let game_search = sys::SteamAPI_SteamGameSearch_v001();
+            debug_assert!(!game_search.is_null());
+            GameSearch {
+                game_search,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam networking sockets interface
+    pub fn networking_sockets(&self) -> NetworkingSockets<Manager> {
+        unsafe {
+            let sockets = sys::SteamAPI_SteamNetworkingSockets_v008();
+            debug_assert!(!sockets.is_null());
+            NetworkingSockets {
+                sockets,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam networking utils interface
+    pub fn networking_utils(&self) -> NetworkingUtils<Manager> {
+        unsafe {
+            let utils = sys::SteamAPI_SteamNetworkingUtils_v003();
+            debug_assert!(!utils.is_null());
+            NetworkingUtils {
+                utils,
+                _inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam http interface
+    pub fn http(&self) -> HTTP<Manager> {
+        unsafe {
+            let http = sys::SteamAPI_SteamHTTP_v003();
+            debug_assert!(!http.is_null());
+            HTTP {
+                http,
+                inner: self.inner.clone(),
+            }
+        }
+    }
 }
 
 /// Used to separate client and game server modes
@@ -340,6 +680,15 @@ impl Drop for ClientManager {
     }
 }
 
+/// Replaces the contents of `buf` with a string returned by steam,
+/// reusing its allocation.
+pub(crate) unsafe fn copy_cstr_into(ptr: *const libc::c_char, buf: &mut String) {
+    buf.clear();
+    if !ptr.is_null() {
+        buf.push_str(&CStr::from_ptr(ptr).to_string_lossy());
+    }
+}
+
 /// A user's steam id
 #[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -371,17 +720,307 @@ impl SteamId {
             AccountId(bits.m_comp.m_unAccountID())
         }
     }
+
+    fn from_parts(universe: u32, account_type: u32, instance: u32, account_id: u32) -> SteamId {
+        SteamId(
+            (u64::from(universe & 0xFF) << 56)
+                | (u64::from(account_type & 0xF) << 52)
+                | (u64::from(instance & 0xF_FF_FF) << 32)
+                | u64::from(account_id)
+        )
+    }
+
+    /// Creates the steam id of an individual user in the public
+    /// universe from their account id
+    pub fn new_individual(account_id: AccountId) -> SteamId {
+        SteamId::from_parts(
+            sys::EUniverse::k_EUniversePublic as u32,
+            sys::EAccountType::k_EAccountTypeIndividual as u32,
+            STEAM_USER_DESKTOP_INSTANCE,
+            account_id.0,
+        )
+    }
+
+    /// Creates a steam id from its individual components
+    pub fn from_components(universe: Universe, account_type: AccountType, instance: u32, account_id: AccountId) -> SteamId {
+        SteamId::from_parts(universe as u32, account_type as u32, instance, account_id.0)
+    }
+
+    /// Returns the universe this steam id belongs to
+    pub fn universe(&self) -> Universe {
+        match self.universe_bits() {
+            1 => Universe::Public,
+            2 => Universe::Beta,
+            3 => Universe::Internal,
+            4 => Universe::Dev,
+            _ => Universe::Invalid,
+        }
+    }
+
+    /// Returns the type of account this steam id refers to
+    pub fn account_type(&self) -> AccountType {
+        match self.account_type_bits() {
+            1 => AccountType::Individual,
+            2 => AccountType::Multiseat,
+            3 => AccountType::GameServer,
+            4 => AccountType::AnonGameServer,
+            5 => AccountType::Pending,
+            6 => AccountType::ContentServer,
+            7 => AccountType::Clan,
+            8 => AccountType::Chat,
+            9 => AccountType::ConsoleUser,
+            10 => AccountType::AnonUser,
+            _ => AccountType::Invalid,
+        }
+    }
+
+    /// Returns the instance of the steam id.
+    ///
+    /// For chat ids this also contains flags marking clan chats
+    /// and lobbies.
+    pub fn instance(&self) -> u32 {
+        self.instance_bits()
+    }
+
+    /// Returns whether this steam id refers to an individual user
+    pub fn is_individual(&self) -> bool {
+        self.account_type() == AccountType::Individual
+    }
+
+    /// Returns whether this steam id refers to a clan or group
+    pub fn is_clan(&self) -> bool {
+        self.account_type() == AccountType::Clan
+    }
+
+    /// Returns whether this steam id refers to a matchmaking lobby
+    pub fn is_lobby(&self) -> bool {
+        self.account_type() == AccountType::Chat
+            && self.instance_bits() & CHAT_INSTANCE_FLAG_LOBBY != 0
+    }
+
+    /// Returns whether this steam id refers to a persistent or
+    /// anonymous game server
+    pub fn is_game_server(&self) -> bool {
+        matches!(self.account_type(), AccountType::GameServer | AccountType::AnonGameServer)
+    }
+
+    /// Returns whether this steam id is well formed.
+    ///
+    /// This doesn't check whether the account actually exists.
+    pub fn is_valid(&self) -> bool {
+        let account_id = self.0 as u32;
+        if self.universe() == Universe::Invalid {
+            return false;
+        }
+        match self.account_type() {
+            AccountType::Invalid => false,
+            AccountType::Individual => account_id != 0 && self.instance_bits() <= STEAM_USER_WEB_INSTANCE,
+            AccountType::Clan => account_id != 0 && self.instance_bits() == 0,
+            AccountType::GameServer => account_id != 0,
+            _ => true,
+        }
+    }
+
+    fn universe_bits(&self) -> u32 {
+        (self.0 >> 56) as u32
+    }
+
+    fn account_type_bits(&self) -> u32 {
+        ((self.0 >> 52) & 0xF) as u32
+    }
+
+    fn instance_bits(&self) -> u32 {
+        ((self.0 >> 32) & 0xF_FF_FF) as u32
+    }
+
+    /// Parses a steam id in the steam2 format, `STEAM_X:Y:Z`.
+    ///
+    /// Steam2 ids can only represent individual accounts.
+    pub fn from_steam2(id: &str) -> Result<SteamId, InvalidSteamId> {
+        let rest = id.strip_prefix("STEAM_").ok_or(InvalidSteamId)?;
+        let mut parts = rest.split(':');
+        let universe: u32 = parse_part(parts.next())?;
+        let low: u32 = parse_part(parts.next())?;
+        let high: u32 = parse_part(parts.next())?;
+        if parts.next().is_some() || universe > MAX_UNIVERSE || low > 1 || high > (u32::MAX >> 1) {
+            return Err(InvalidSteamId);
+        }
+        // Older games report the public universe as 0
+        let universe = if universe == 0 { 1 } else { universe };
+        Ok(SteamId::from_parts(
+            universe,
+            sys::EAccountType::k_EAccountTypeIndividual as u32,
+            STEAM_USER_DESKTOP_INSTANCE,
+            (high << 1) | low,
+        ))
+    }
+
+    /// Parses a steam id in the steam3 format, for example `[U:1:22202]`.
+    ///
+    /// The surrounding brackets are optional. Universes and instances
+    /// that don't fit in a steam id are rejected.
+    pub fn from_steam3(id: &str) -> Result<SteamId, InvalidSteamId> {
+        let id = id.strip_prefix('[')
+            .and_then(|v| v.strip_suffix(']'))
+            .unwrap_or(id);
+        let mut parts = id.split(':');
+        let letter = parts.next().ok_or(InvalidSteamId)?;
+        let universe: u32 = parse_part(parts.next())?;
+        let account_id: u32 = parse_part(parts.next())?;
+        let instance: Option<u32> = match parts.next() {
+            Some(v) => Some(parse_part(Some(v))?),
+            None => None,
+        };
+        if parts.next().is_some() || universe > MAX_UNIVERSE || instance.map_or(false, |v| v > MAX_INSTANCE) {
+            return Err(InvalidSteamId);
+        }
+        use sys::EAccountType::*;
+        let (account_type, default_instance) = match letter {
+            "I" => (k_EAccountTypeInvalid, 0),
+            "U" => (k_EAccountTypeIndividual, STEAM_USER_DESKTOP_INSTANCE),
+            "M" => (k_EAccountTypeMultiseat, 0),
+            "G" => (k_EAccountTypeGameServer, 0),
+            "A" => (k_EAccountTypeAnonGameServer, 0),
+            "P" => (k_EAccountTypePending, 0),
+            "C" => (k_EAccountTypeContentServer, 0),
+            "g" => (k_EAccountTypeClan, 0),
+            "T" => (k_EAccountTypeChat, 0),
+            "c" => (k_EAccountTypeChat, CHAT_INSTANCE_FLAG_CLAN),
+            "L" => (k_EAccountTypeChat, CHAT_INSTANCE_FLAG_LOBBY),
+            "a" => (k_EAccountTypeAnonUser, 0),
+            _ => return Err(InvalidSteamId),
+        };
+        Ok(SteamId::from_parts(
+            universe,
+            account_type as u32,
+            instance.unwrap_or(default_instance),
+            account_id,
+        ))
+    }
+
+    /// Formats the steam id in the steam2 format, `STEAM_X:Y:Z`.
+    ///
+    /// Only meaningful for individual accounts.
+    pub fn to_steam2(&self) -> String {
+        let account_id = self.0 as u32;
+        format!("STEAM_{}:{}:{}", self.universe_bits(), account_id & 1, account_id >> 1)
+    }
+
+    /// Formats the steam id in the steam3 format, for example `[U:1:22202]`.
+    pub fn to_steam3(&self) -> String {
+        let instance = self.instance_bits();
+        let account_type = self.account_type_bits();
+        let letter = match account_type {
+            0 => 'I',
+            1 => 'U',
+            2 => 'M',
+            3 => 'G',
+            4 => 'A',
+            5 => 'P',
+            6 => 'C',
+            7 => 'g',
+            8 if instance & CHAT_INSTANCE_FLAG_CLAN != 0 => 'c',
+            8 if instance & CHAT_INSTANCE_FLAG_LOBBY != 0 => 'L',
+            8 => 'T',
+            10 => 'a',
+            _ => 'i',
+        };
+        let show_instance = match letter {
+            'U' => instance != STEAM_USER_DESKTOP_INSTANCE,
+            'M' | 'G' | 'A' => instance != 0,
+            _ => false,
+        };
+        if show_instance {
+            format!("[{}:{}:{}:{}]", letter, self.universe_bits(), self.0 as u32, instance)
+        } else {
+            format!("[{}:{}:{}]", letter, self.universe_bits(), self.0 as u32)
+        }
+    }
+}
+
+const MAX_UNIVERSE: u32 = 0xFF;
+const MAX_INSTANCE: u32 = 0xF_FF_FF;
+const STEAM_USER_DESKTOP_INSTANCE: u32 = 1;
+const STEAM_USER_WEB_INSTANCE: u32 = 4;
+const CHAT_INSTANCE_FLAG_CLAN: u32 = 0x8_00_00;
+const CHAT_INSTANCE_FLAG_LOBBY: u32 = 0x4_00_00;
+
+fn parse_part<T: std::str::FromStr>(part: Option<&str>) -> Result<T, InvalidSteamId> {
+    part.ok_or(InvalidSteamId)?
+        .parse()
+        .map_err(|_| InvalidSteamId)
+}
+
+/// Parses the 64 bit decimal form of a steam id
+impl std::str::FromStr for SteamId {
+    type Err = InvalidSteamId;
+
+    fn from_str(s: &str) -> Result<SteamId, InvalidSteamId> {
+        s.parse().map(SteamId).map_err(|_| InvalidSteamId)
+    }
 }
 
+/// Formats the steam id in its 64 bit decimal form
+impl fmt::Display for SteamId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Returned when a string couldn't be parsed as a steam id
+#[derive(Clone, Copy, Debug, Fail, PartialEq, Eq)]
+#[fail(display = "invalid steam id")]
+pub struct InvalidSteamId;
+
 /// A user's account id
 #[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AccountId(pub(crate) u32);
 
+impl AccountId {
+    /// Creates an `AccountId` from a raw 32 bit value.
+    pub fn from_raw(id: u32) -> AccountId {
+        AccountId(id)
+    }
+
+    /// Returns the raw 32 bit value of the account id
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+}
+
+/// The steam universe a steam id belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Universe {
+    Invalid = 0,
+    Public = 1,
+    Beta = 2,
+    Internal = 3,
+    Dev = 4,
+}
+
+/// The type of account a steam id refers to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AccountType {
+    Invalid = 0,
+    Individual = 1,
+    Multiseat = 2,
+    GameServer = 3,
+    AnonGameServer = 4,
+    Pending = 5,
+    ContentServer = 6,
+    Clan = 7,
+    Chat = 8,
+    ConsoleUser = 9,
+    AnonUser = 10,
+}
+
 /// A game id
 ///
 /// Combines `AppId` and other information
-#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GameId(pub(crate) u64);
 
@@ -402,11 +1041,126 @@ impl GameId {
         self.0
     }
 
+    /// Creates a `GameId` for a steam app
+    pub fn new_app(app_id: AppId) -> GameId {
+        GameId::from_parts(app_id, GameIdType::App, 0)
+    }
+
+    /// Creates a `GameId` for a mod of a steam app.
+    ///
+    /// `mod_path` is the name of the mod's directory, as used by
+    /// source and goldsrc engine games.
+    pub fn new_mod(app_id: AppId, mod_path: &str) -> GameId {
+        let mod_id = crc32(0, mod_path.as_bytes());
+        GameId::from_parts(app_id, GameIdType::GameMod, mod_id | 0x80_00_00_00)
+    }
+
+    /// Creates a `GameId` for a non-steam game added to the
+    /// user's library as a shortcut
+    pub fn new_shortcut(exe_path: &str, app_name: &str) -> GameId {
+        let crc = crc32(0, exe_path.as_bytes());
+        let crc = crc32(crc, app_name.as_bytes());
+        GameId::from_parts(AppId(0), GameIdType::Shortcut, crc | 0x80_00_00_00)
+    }
+
+    /// Creates a `GameId` from its individual components
+    pub fn from_parts(app_id: AppId, game_type: GameIdType, mod_id: u32) -> GameId {
+        GameId(
+            u64::from(app_id.0 & 0xFF_FF_FF)
+                | (u64::from(game_type as u8) << 24)
+                | (u64::from(mod_id) << 32)
+        )
+    }
+
     /// Returns the app id of this game
     pub fn app_id(&self) -> AppId {
-        // TODO: Relies on internal details
         AppId((self.0 & 0xFF_FF_FF) as u32)
     }
+
+    /// Returns the type of game this id refers to
+    pub fn game_type(&self) -> GameIdType {
+        match (self.0 >> 24) & 0xFF {
+            0 => GameIdType::App,
+            1 => GameIdType::GameMod,
+            2 => GameIdType::Shortcut,
+            _ => GameIdType::P2P,
+        }
+    }
+
+    /// Returns the mod id of this game.
+    ///
+    /// Only meaningful for mods, shortcuts and p2p files.
+    pub fn mod_id(&self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+
+    /// Returns whether this id refers to a steam app
+    pub fn is_steam_app(&self) -> bool {
+        self.game_type() == GameIdType::App
+    }
+
+    /// Returns whether this id refers to a mod of a steam app
+    pub fn is_mod(&self) -> bool {
+        self.game_type() == GameIdType::GameMod
+    }
+
+    /// Returns whether this id refers to a non-steam shortcut
+    pub fn is_shortcut(&self) -> bool {
+        self.game_type() == GameIdType::Shortcut
+    }
+
+    /// Returns whether this id refers to a p2p file
+    pub fn is_p2p_file(&self) -> bool {
+        self.game_type() == GameIdType::P2P
+    }
+
+    /// Returns whether this id is well formed
+    pub fn is_valid(&self) -> bool {
+        let app_id = self.app_id().0;
+        let mod_id = self.mod_id();
+        match self.game_type() {
+            GameIdType::App => app_id != 0,
+            GameIdType::GameMod => app_id != 0 && mod_id & 0x80_00_00_00 != 0,
+            GameIdType::Shortcut => mod_id & 0x80_00_00_00 != 0,
+            GameIdType::P2P => app_id == 0 && mod_id & 0x80_00_00_00 != 0,
+        }
+    }
+}
+
+impl From<AppId> for GameId {
+    fn from(app_id: AppId) -> GameId {
+        GameId::new_app(app_id)
+    }
+}
+
+/// The type of game a `GameId` refers to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GameIdType {
+    /// A steam app
+    App = 0,
+    /// A mod of a steam app
+    GameMod = 1,
+    /// A non-steam game added to the library as a shortcut
+    Shortcut = 2,
+    /// A p2p file
+    P2P = 3,
+}
+
+/// The CRC-32 steam uses to derive mod and shortcut ids
+fn crc32(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for &b in data {
+        crc ^= u32::from(b);
+        for _ in 0 .. 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xED_B8_83_20
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
 }
 
 #[cfg(test)]
@@ -453,4 +1207,47 @@ mod tests {
             ::std::thread::sleep(::std::time::Duration::from_millis(100));
         }
     }
+
+    #[test]
+    fn steam_id_formats() {
+        let id = SteamId(76561198174976054);
+        assert_eq!(id.to_steam2(), "STEAM_1:0:107355163");
+        assert_eq!(id.to_steam3(), "[U:1:214710326]");
+        assert_eq!(id.to_string(), "76561198174976054");
+        assert_eq!(SteamId::from_steam2("STEAM_0:0:107355163"), Ok(id));
+        assert_eq!(SteamId::from_steam2("STEAM_1:0:107355163"), Ok(id));
+        assert_eq!(SteamId::from_steam3("[U:1:214710326]"), Ok(id));
+        assert_eq!("76561198174976054".parse(), Ok(id));
+        assert!(SteamId::from_steam2("STEAM_1:2:107355163").is_err());
+        assert!(SteamId::from_steam3("[X:1:214710326]").is_err());
+        assert!(SteamId::from_steam2("STEAM_256:0:107355163").is_err());
+        assert!(SteamId::from_steam3("[U:256:214710326]").is_err());
+        assert!(SteamId::from_steam3("[U:1:214710326:1048576]").is_err());
+        assert_eq!(SteamId::from_steam3("[U:1:214710326:1]"), Ok(id));
+
+        assert_eq!(SteamId::new_individual(AccountId(214710326)), id);
+        assert_eq!(id.universe(), Universe::Public);
+        assert_eq!(id.account_type(), AccountType::Individual);
+        assert_eq!(id.account_id(), AccountId(214710326));
+        assert!(id.is_valid());
+        assert!(!id.is_lobby());
+        assert!(SteamId::from_steam3("[L:1:1234]").unwrap().is_lobby());
+        assert!(!SteamId(0).is_valid());
+    }
+
+    #[test]
+    fn game_id_parts() {
+        let id = GameId::new_app(AppId(480));
+        assert_eq!(id.raw(), 480);
+        assert!(id.is_steam_app());
+        assert!(id.is_valid());
+
+        let id = GameId::new_mod(AppId(215), "cstrike");
+        assert_eq!(id.app_id(), AppId(215));
+        assert_eq!(id.game_type(), GameIdType::GameMod);
+        assert_eq!(id.mod_id(), crc32(0, b"cstrike") | 0x80_00_00_00);
+        assert!(id.is_valid());
+
+        assert_eq!(crc32(0, b"123456789"), 0xCB_F4_39_26);
+    }
 }
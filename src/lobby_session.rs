@@ -0,0 +1,263 @@
+use super::*;
+use std::collections::VecDeque;
+
+/// Events produced by `LobbySession::poll`
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LobbyEvent {
+    /// A user joined the lobby
+    MemberJoined(SteamId),
+    /// A user left, disconnected from or was removed from the lobby
+    MemberLeft(SteamId),
+    /// Ownership of the lobby moved to another member
+    OwnerChanged(SteamId),
+    /// The lobby's metadata changed
+    LobbyDataChanged,
+    /// The metadata of a member changed
+    MemberDataChanged(SteamId),
+    /// A lobby chat message was received
+    ChatMessage {
+        sender: SteamId,
+        message: Vec<u8>,
+    },
+    /// A p2p message was received from a member
+    PeerMessage {
+        sender: SteamId,
+        data: Vec<u8>,
+    },
+}
+
+enum RawEvent {
+    ChatUpdate(LobbyChatUpdate),
+    DataUpdate(LobbyDataUpdate),
+    ChatMsg(LobbyChatMsg),
+    SessionRequest(SteamId),
+}
+
+/// A joined lobby together with p2p messaging between its members.
+///
+/// Combines membership, lobby and member data, lobby chat, owner
+/// migration and p2p messages into a single event queue that is
+/// drained with `poll` after running callbacks. Peer messages are
+/// sent over a p2p channel of `Networking` and sessions from other
+/// lobby members are accepted automatically.
+///
+/// Every packet received on the session's channel is reported as a
+/// `PeerMessage`, so other p2p traffic should use a different channel.
+///
+/// The lobby is left when the session is dropped.
+pub struct LobbySession<Manager> {
+    lobby: LobbyId,
+    local_user: SteamId,
+    owner: SteamId,
+    members: Vec<SteamId>,
+
+    matchmaking: Matchmaking<Manager>,
+    networking: Networking<Manager>,
+    channel: i32,
+
+    raw_events: Arc<Mutex<VecDeque<RawEvent>>>,
+    events: VecDeque<LobbyEvent>,
+    _callbacks: Vec<CallbackHandle<Manager>>,
+}
+
+impl <Manager> LobbySession<Manager> {
+    /// Starts a session for a lobby the current user has already
+    /// created or joined.
+    pub fn new(client: &Client<Manager>, lobby: LobbyId) -> LobbySession<Manager> {
+        let raw_events = Arc::new(Mutex::new(VecDeque::new()));
+
+        let events = Arc::clone(&raw_events);
+        let chat_update = client.register_callback(move |v: LobbyChatUpdate| {
+            if v.lobby == lobby {
+                events.lock().unwrap().push_back(RawEvent::ChatUpdate(v));
+            }
+        });
+        let events = Arc::clone(&raw_events);
+        let data_update = client.register_callback(move |v: LobbyDataUpdate| {
+            if v.lobby == lobby {
+                events.lock().unwrap().push_back(RawEvent::DataUpdate(v));
+            }
+        });
+        let events = Arc::clone(&raw_events);
+        let chat_msg = client.register_callback(move |v: LobbyChatMsg| {
+            if v.lobby == lobby {
+                events.lock().unwrap().push_back(RawEvent::ChatMsg(v));
+            }
+        });
+        let events = Arc::clone(&raw_events);
+        let session_request = client.register_callback(move |v: P2PSessionRequest| {
+            events.lock().unwrap().push_back(RawEvent::SessionRequest(v.remote));
+        });
+
+        let matchmaking = client.matchmaking();
+        let owner = matchmaking.lobby_owner(lobby);
+        let members = matchmaking.lobby_members(lobby);
+        LobbySession {
+            lobby,
+            local_user: client.user().steam_id(),
+            owner,
+            members,
+
+            matchmaking,
+            networking: client.networking(),
+            channel: 0,
+
+            raw_events,
+            events: VecDeque::new(),
+            _callbacks: vec![chat_update, data_update, chat_msg, session_request],
+        }
+    }
+
+    /// Sets the p2p channel peer messages are sent and received on.
+    /// Defaults to 0.
+    ///
+    /// Every member must use the same channel.
+    pub fn channel(mut self, channel: i32) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Returns the id of the lobby
+    pub fn lobby(&self) -> LobbyId {
+        self.lobby
+    }
+
+    /// Returns the current owner of the lobby
+    pub fn owner(&self) -> SteamId {
+        self.owner
+    }
+
+    /// Returns whether the current user owns the lobby
+    pub fn is_owner(&self) -> bool {
+        self.owner == self.local_user
+    }
+
+    /// Returns the members of the lobby, including the current user
+    pub fn members(&self) -> &[SteamId] {
+        &self.members
+    }
+
+    /// Returns the lobby's metadata associated with the key
+    pub fn data(&self, key: &str) -> Option<String> {
+        self.matchmaking.lobby_data(self.lobby, key)
+    }
+
+    /// Sets the lobby's metadata associated with the key.
+    ///
+    /// Returns false if the current user isn't the owner.
+    pub fn set_data(&self, key: &str, value: &str) -> bool {
+        self.matchmaking.set_lobby_data(self.lobby, key, value)
+    }
+
+    /// Returns a member's metadata associated with the key
+    pub fn member_data(&self, member: SteamId, key: &str) -> Option<String> {
+        self.matchmaking.lobby_member_data(self.lobby, member, key)
+    }
+
+    /// Sets the current user's metadata associated with the key
+    pub fn set_member_data(&self, key: &str, value: &str) {
+        self.matchmaking.set_lobby_member_data(self.lobby, key, value)
+    }
+
+    /// Sends a chat message to every member of the lobby
+    pub fn send_chat(&self, message: &[u8]) -> bool {
+        self.matchmaking.send_lobby_chat_msg(self.lobby, message)
+    }
+
+    /// Sends a p2p message to a single member
+    pub fn send_to(&self, member: SteamId, send_type: SendType, data: &[u8]) -> bool {
+        self.networking.send_p2p_packet_on_channel(member, send_type, data, self.channel)
+    }
+
+    /// Sends a p2p message to every other member.
+    ///
+    /// Returns false if sending to any member failed.
+    pub fn broadcast(&self, send_type: SendType, data: &[u8]) -> bool {
+        let mut ok = true;
+        for &member in &self.members {
+            if member != self.local_user {
+                ok &= self.networking.send_p2p_packet_on_channel(member, send_type.clone(), data, self.channel);
+            }
+        }
+        ok
+    }
+
+    /// Returns the next event for the lobby if there is one.
+    ///
+    /// This should be called until it returns `None` after running
+    /// callbacks.
+    pub fn poll(&mut self) -> Option<LobbyEvent> {
+        if self.events.is_empty() {
+            self.process_raw_events();
+            while let Some(size) = self.networking.is_p2p_packet_available_on_channel(self.channel) {
+                let mut data = vec![0; size];
+                match self.networking.read_p2p_packet_from_channel(&mut data, self.channel) {
+                    Some((sender, len)) => {
+                        data.truncate(len);
+                        self.events.push_back(LobbyEvent::PeerMessage { sender, data });
+                    },
+                    None => break,
+                }
+            }
+        }
+        self.events.pop_front()
+    }
+
+    fn process_raw_events(&mut self) {
+        let raw_events = std::mem::take(&mut *self.raw_events.lock().unwrap());
+        for event in raw_events {
+            match event {
+                RawEvent::ChatUpdate(v) => {
+                    if v.member_state_change.contains(ChatMemberStateChange::ENTERED) {
+                        if !self.members.contains(&v.user_changed) {
+                            self.members.push(v.user_changed);
+                        }
+                        self.events.push_back(LobbyEvent::MemberJoined(v.user_changed));
+                    } else {
+                        self.members.retain(|&member| member != v.user_changed);
+                        self.networking.close_p2p_session(v.user_changed);
+                        self.events.push_back(LobbyEvent::MemberLeft(v.user_changed));
+                    }
+                    self.check_owner();
+                },
+                RawEvent::DataUpdate(v) => {
+                    if v.member.0 == self.lobby.0 {
+                        self.events.push_back(LobbyEvent::LobbyDataChanged);
+                    } else {
+                        self.events.push_back(LobbyEvent::MemberDataChanged(v.member));
+                    }
+                    self.check_owner();
+                },
+                RawEvent::ChatMsg(v) => {
+                    let (sender, message) = self.matchmaking.lobby_chat_entry(self.lobby, v.chat_id);
+                    self.events.push_back(LobbyEvent::ChatMessage { sender, message });
+                },
+                RawEvent::SessionRequest(remote) => {
+                    if self.members.contains(&remote) {
+                        self.networking.accept_p2p_session(remote);
+                    }
+                },
+            }
+        }
+    }
+
+    fn check_owner(&mut self) {
+        let owner = self.matchmaking.lobby_owner(self.lobby);
+        if owner != self.owner {
+            self.owner = owner;
+            self.events.push_back(LobbyEvent::OwnerChanged(owner));
+        }
+    }
+}
+
+impl <Manager> Drop for LobbySession<Manager> {
+    fn drop(&mut self) {
+        for &member in &self.members {
+            if member != self.local_user {
+                self.networking.close_p2p_session(member);
+            }
+        }
+        self.matchmaking.leave_lobby(self.lobby);
+    }
+}
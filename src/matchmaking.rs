@@ -10,6 +10,15 @@ pub struct Matchmaking<Manager> {
 
 const CALLBACK_BASE_ID: i32 = 500;
 
+/// The most members a lobby can have
+pub const LOBBY_MEMBER_LIMIT: u32 = 250;
+/// The longest a lobby or member data key can be, in bytes
+pub const LOBBY_DATA_KEY_MAX: usize = sys::k_nMaxLobbyKeyLength as usize;
+/// The longest a lobby or member data value can be, in bytes
+pub const LOBBY_DATA_VALUE_MAX: usize = sys::k_cubChatMetadataMax as usize;
+/// The longest a lobby chat message can be, in bytes
+pub const LOBBY_CHAT_MSG_MAX: usize = 4 * 1024;
+
 /// The visibility of a lobby
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -20,7 +29,7 @@ pub enum LobbyType {
     Invisible,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LobbyId(pub(crate) u64);
 
@@ -43,6 +52,19 @@ impl LobbyId {
 }
 
 impl <Manager> Matchmaking<Manager> {
+    /// Returns the raw `ISteamMatchmaking` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamMatchmaking {
+        self.mm
+    }
+
 
     pub fn request_lobby_list<F>(&self, mut cb: F)
         where F: FnMut(SResult<Vec<LobbyId>>) + 'static + Send
@@ -79,7 +101,7 @@ impl <Manager> Matchmaking<Manager> {
     pub fn create_lobby<F>(&self, ty: LobbyType, max_members: u32, mut cb: F)
         where F: FnMut(SResult<LobbyId>) + 'static + Send
     {
-        assert!(max_members <= 250); // Steam API limits
+        assert!(max_members <= LOBBY_MEMBER_LIMIT); // Steam API limits
         unsafe {
             let ty = match ty {
                 LobbyType::Private => sys::ELobbyType::k_ELobbyTypePrivate,
@@ -112,9 +134,7 @@ impl <Manager> Matchmaking<Manager> {
             register_call_result::<sys::LobbyEnter_t, _, _>(
                 &self.inner, api_call, CALLBACK_BASE_ID + 4,
                 move |v, io_error| {
-                    cb(if io_error {
-                        Err(())
-                    } else if v.m_EChatRoomEnterResponse != 1 {
+                    cb(if io_error || v.m_EChatRoomEnterResponse != 1 {
                         Err(())
                     } else {
                         Ok(LobbyId(v.m_ulSteamIDLobby))
@@ -162,6 +182,140 @@ impl <Manager> Matchmaking<Manager> {
         }
     }
 
+    /// Returns the lobby metadata associated with the key.
+    ///
+    /// Returns `None` if the key isn't set.
+    pub fn lobby_data(&self, lobby: LobbyId, key: &str) -> Option<String> {
+        let key = CString::new(key).unwrap();
+        let mut value = String::new();
+        if self.lobby_data_into(lobby, &key, &mut value) {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Writes the lobby metadata associated with the key into `buf`,
+    /// replacing its contents.
+    ///
+    /// Unlike `lobby_data` this doesn't allocate once `buf` is large
+    /// enough. Returns false, leaving `buf` empty, if the key isn't set.
+    pub fn lobby_data_into(&self, lobby: LobbyId, key: &CStr, buf: &mut String) -> bool {
+        unsafe {
+            let value = sys::SteamAPI_ISteamMatchmaking_GetLobbyData(self.mm, lobby.0, key.as_ptr());
+            copy_cstr_into(value, buf);
+            !buf.is_empty()
+        }
+    }
+
+    /// Sets the lobby metadata associated with the key.
+    ///
+    /// Only the owner of the lobby can set lobby data. Returns false
+    /// if the data couldn't be set. Steam truncates data that is too
+    /// long, `validate_lobby_data` can be used to check it first.
+    pub fn set_lobby_data(&self, lobby: LobbyId, key: &str, value: &str) -> bool {
+        unsafe {
+            let key = CString::new(key).unwrap();
+            let value = CString::new(value).unwrap();
+            sys::SteamAPI_ISteamMatchmaking_SetLobbyData(self.mm, lobby.0, key.as_ptr(), value.as_ptr())
+        }
+    }
+
+    /// Returns the metadata of a lobby member associated with the key.
+    ///
+    /// Returns `None` if the key isn't set.
+    pub fn lobby_member_data(&self, lobby: LobbyId, member: SteamId, key: &str) -> Option<String> {
+        unsafe {
+            let key = CString::new(key).unwrap();
+            let value = sys::SteamAPI_ISteamMatchmaking_GetLobbyMemberData(self.mm, lobby.0, member.0, key.as_ptr());
+            let mut buf = String::new();
+            copy_cstr_into(value, &mut buf);
+            if buf.is_empty() {
+                None
+            } else {
+                Some(buf)
+            }
+        }
+    }
+
+    /// Sets the current user's metadata in the lobby associated
+    /// with the key.
+    ///
+    /// Steam truncates data that is too long, `validate_lobby_data`
+    /// can be used to check it first.
+    pub fn set_lobby_member_data(&self, lobby: LobbyId, key: &str, value: &str) {
+        unsafe {
+            let key = CString::new(key).unwrap();
+            let value = CString::new(value).unwrap();
+            sys::SteamAPI_ISteamMatchmaking_SetLobbyMemberData(self.mm, lobby.0, key.as_ptr(), value.as_ptr());
+        }
+    }
+
+    /// Broadcasts a chat message to all members of the lobby.
+    ///
+    /// Messages can be at most 4KB. Members receive a `LobbyChatMsg`
+    /// callback and can read the message with `lobby_chat_entry`.
+    pub fn send_lobby_chat_msg(&self, lobby: LobbyId, msg: &[u8]) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMatchmaking_SendLobbyChatMsg(self.mm, lobby.0, msg.as_ptr() as *const _, msg.len() as _)
+        }
+    }
+
+    /// Reads a chat message from a `LobbyChatMsg` callback.
+    ///
+    /// Returns the sender and the message.
+    pub fn lobby_chat_entry(&self, lobby: LobbyId, chat_id: u32) -> (SteamId, Vec<u8>) {
+        unsafe {
+            let mut user: sys::CSteamID = std::mem::zeroed();
+            let mut ty = sys::EChatEntryType::k_EChatEntryTypeInvalid;
+            let mut buf = vec![0u8; 4096];
+            let len = sys::SteamAPI_ISteamMatchmaking_GetLobbyChatEntry(
+                self.mm, lobby.0, chat_id as _,
+                &mut user, buf.as_mut_ptr() as *mut _, buf.len() as _,
+                &mut ty,
+            );
+            buf.truncate(len.max(0) as usize);
+            (SteamId(user.m_steamid.m_unAll64Bits), buf)
+        }
+    }
+
+    /// Returns the most members the lobby can have, or `None` if
+    /// the lobby's data hasn't been received
+    pub fn lobby_member_limit(&self, lobby: LobbyId) -> Option<u32> {
+        unsafe {
+            let limit = sys::SteamAPI_ISteamMatchmaking_GetLobbyMemberLimit(self.mm, lobby.0);
+            if limit <= 0 {
+                None
+            } else {
+                Some(limit as u32)
+            }
+        }
+    }
+
+    /// Changes the most members the lobby can have.
+    ///
+    /// Only the owner can do this. Fails if the limit is above
+    /// `LOBBY_MEMBER_LIMIT` or steam rejects the change.
+    pub fn set_lobby_member_limit(&self, lobby: LobbyId, max_members: u32) -> Result<(), LobbyLimitError> {
+        validate_lobby_member_limit(max_members)?;
+        unsafe {
+            if sys::SteamAPI_ISteamMatchmaking_SetLobbyMemberLimit(self.mm, lobby.0, max_members as _) {
+                Ok(())
+            } else {
+                Err(LobbyLimitError::Rejected)
+            }
+        }
+    }
+
+    /// Transfers ownership of the lobby to another member.
+    ///
+    /// Only the current owner can do this. Returns false on failure.
+    pub fn set_lobby_owner(&self, lobby: LobbyId, new_owner: SteamId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMatchmaking_SetLobbyOwner(self.mm, lobby.0, new_owner.0)
+        }
+    }
+
     /// Sets whether or not a lobby is joinable by other players. This always defaults to enabled
     /// for a new lobby.
     ///
@@ -180,6 +334,163 @@ impl <Manager> Matchmaking<Manager> {
     }
 }
 
+/// Errors from validating lobby limits
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Fail)]
+pub enum LobbyLimitError {
+    /// The data key is empty
+    #[fail(display = "lobby data key is empty")]
+    EmptyKey,
+    /// The data key is longer than `LOBBY_DATA_KEY_MAX`
+    #[fail(display = "lobby data key is too long ({} bytes)", _0)]
+    KeyTooLong(usize),
+    /// The data value is longer than `LOBBY_DATA_VALUE_MAX`
+    #[fail(display = "lobby data value is too long ({} bytes)", _0)]
+    ValueTooLong(usize),
+    /// The key or value contains a nul byte
+    #[fail(display = "lobby data contains a nul byte")]
+    ContainsNul,
+    /// The chat message is longer than `LOBBY_CHAT_MSG_MAX`
+    #[fail(display = "lobby chat message is too long ({} bytes)", _0)]
+    MessageTooLong(usize),
+    /// The member limit is above `LOBBY_MEMBER_LIMIT`
+    #[fail(display = "too many lobby members ({})", _0)]
+    TooManyMembers(u32),
+    /// Steam rejected the change, e.g. because the current user
+    /// doesn't own the lobby
+    #[fail(display = "lobby change rejected")]
+    Rejected,
+}
+
+/// Checks that a lobby or member data key and value are within
+/// steam's limits.
+///
+/// Steam silently truncates data that is too long, so checking it
+/// first avoids losing data.
+pub fn validate_lobby_data(key: &str, value: &str) -> Result<(), LobbyLimitError> {
+    if key.is_empty() {
+        Err(LobbyLimitError::EmptyKey)
+    } else if key.len() > LOBBY_DATA_KEY_MAX {
+        Err(LobbyLimitError::KeyTooLong(key.len()))
+    } else if value.len() > LOBBY_DATA_VALUE_MAX {
+        Err(LobbyLimitError::ValueTooLong(value.len()))
+    } else if key.contains('\0') || value.contains('\0') {
+        Err(LobbyLimitError::ContainsNul)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that a lobby chat message is within steam's limits
+pub fn validate_lobby_chat_msg(msg: &[u8]) -> Result<(), LobbyLimitError> {
+    if msg.len() > LOBBY_CHAT_MSG_MAX {
+        Err(LobbyLimitError::MessageTooLong(msg.len()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that a lobby member limit is within steam's limits
+pub fn validate_lobby_member_limit(max_members: u32) -> Result<(), LobbyLimitError> {
+    if max_members > LOBBY_MEMBER_LIMIT {
+        Err(LobbyLimitError::TooManyMembers(max_members))
+    } else {
+        Ok(())
+    }
+}
+
+bitflags! {
+    /// How a lobby member's state changed in a `LobbyChatUpdate`
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[repr(C)]
+    pub struct ChatMemberStateChange: u32 {
+        const ENTERED      = 0x0001;
+        const LEFT         = 0x0002;
+        const DISCONNECTED = 0x0004;
+        const KICKED       = 0x0008;
+        const BANNED       = 0x0010;
+    }
+}
+
+/// Called when the metadata of a lobby or one of its members
+/// changes
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LobbyDataUpdate {
+    pub lobby: LobbyId,
+    /// The member whose data changed. Equal to the lobby id
+    /// if the lobby's own data changed.
+    pub member: SteamId,
+    pub success: bool,
+}
+
+unsafe impl Callback for LobbyDataUpdate {
+    const ID: i32 = CALLBACK_BASE_ID + 5;
+    const SIZE: i32 = ::std::mem::size_of::<sys::LobbyDataUpdate_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::LobbyDataUpdate_t);
+        LobbyDataUpdate {
+            lobby: LobbyId(val.m_ulSteamIDLobby),
+            member: SteamId(val.m_ulSteamIDMember),
+            success: val.m_bSuccess != 0,
+        }
+    }
+}
+
+/// Called when a user joins or leaves a lobby the current user
+/// is in
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LobbyChatUpdate {
+    pub lobby: LobbyId,
+    /// The user who joined or left
+    pub user_changed: SteamId,
+    /// The user who made the change, e.g. the owner when kicking
+    pub making_change: SteamId,
+    pub member_state_change: ChatMemberStateChange,
+}
+
+unsafe impl Callback for LobbyChatUpdate {
+    const ID: i32 = CALLBACK_BASE_ID + 6;
+    const SIZE: i32 = ::std::mem::size_of::<sys::LobbyChatUpdate_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::LobbyChatUpdate_t);
+        LobbyChatUpdate {
+            lobby: LobbyId(val.m_ulSteamIDLobby),
+            user_changed: SteamId(val.m_ulSteamIDUserChanged),
+            making_change: SteamId(val.m_ulSteamIDMakingChange),
+            member_state_change: ChatMemberStateChange::from_bits_truncate(val.m_rgfChatMemberStateChange),
+        }
+    }
+}
+
+/// Called when a chat message is sent to a lobby the current
+/// user is in.
+///
+/// The message can be read with `Matchmaking::lobby_chat_entry`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LobbyChatMsg {
+    pub lobby: LobbyId,
+    pub user: SteamId,
+    pub chat_id: u32,
+}
+
+unsafe impl Callback for LobbyChatMsg {
+    const ID: i32 = CALLBACK_BASE_ID + 7;
+    const SIZE: i32 = ::std::mem::size_of::<sys::LobbyChatMsg_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::LobbyChatMsg_t);
+        LobbyChatMsg {
+            lobby: LobbyId(val.m_ulSteamIDLobby),
+            user: SteamId(val.m_ulSteamIDUser),
+            chat_id: val.m_iChatID,
+        }
+    }
+}
+
 #[test]
 #[serial]
 fn test_lobby() {
@@ -198,3 +509,19 @@ fn test_lobby() {
         ::std::thread::sleep(::std::time::Duration::from_millis(100));
     }
 }
+
+#[test]
+fn lobby_limits() {
+    assert!(validate_lobby_data("name", "My lobby").is_ok());
+    assert_eq!(validate_lobby_data("", "value"), Err(LobbyLimitError::EmptyKey));
+    let long_key = "k".repeat(LOBBY_DATA_KEY_MAX + 1);
+    assert_eq!(validate_lobby_data(&long_key, ""), Err(LobbyLimitError::KeyTooLong(LOBBY_DATA_KEY_MAX + 1)));
+    let long_value = "v".repeat(LOBBY_DATA_VALUE_MAX + 1);
+    assert_eq!(validate_lobby_data("key", &long_value), Err(LobbyLimitError::ValueTooLong(LOBBY_DATA_VALUE_MAX + 1)));
+    assert_eq!(validate_lobby_data("key", "a\0b"), Err(LobbyLimitError::ContainsNul));
+
+    assert!(validate_lobby_chat_msg(&[0; LOBBY_CHAT_MSG_MAX]).is_ok());
+    assert!(validate_lobby_chat_msg(&[0; LOBBY_CHAT_MSG_MAX + 1]).is_err());
+    assert!(validate_lobby_member_limit(LOBBY_MEMBER_LIMIT).is_ok());
+    assert_eq!(validate_lobby_member_limit(251), Err(LobbyLimitError::TooManyMembers(251)));
+}
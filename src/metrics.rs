@@ -0,0 +1,53 @@
+use super::*;
+
+use std::time::{Duration, Instant};
+
+/// A snapshot of how often callbacks have been dispatched and how
+/// long their handlers took to run.
+///
+/// Only available with the `metrics` feature.
+#[derive(Clone, Debug, Default)]
+pub struct CallbackStats {
+    /// Stats for registered callbacks keyed by callback id
+    pub callbacks: HashMap<i32, HandlerStats>,
+    /// Stats for call results keyed by the callback id of the result
+    pub call_results: HashMap<i32, HandlerStats>,
+}
+
+/// Dispatch stats for a single callback type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HandlerStats {
+    /// The number of times the handler was run
+    pub count: u64,
+    /// The total time spent running the handler
+    pub total_time: Duration,
+    /// The longest a single run of the handler took
+    pub max_time: Duration,
+}
+
+impl HandlerStats {
+    /// Returns the average time a single run of the handler took
+    pub fn average_time(&self) -> Duration {
+        if self.count == 0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_nanos((self.total_time.as_nanos() / u128::from(self.count)) as u64)
+        }
+    }
+
+    fn record(&mut self, elapsed: Duration) {
+        self.count += 1;
+        self.total_time += elapsed;
+        self.max_time = self.max_time.max(elapsed);
+    }
+}
+
+impl CallbackStats {
+    pub(crate) fn record_callback(&mut self, id: i32, start: Instant) {
+        self.callbacks.entry(id).or_default().record(start.elapsed());
+    }
+
+    pub(crate) fn record_call_result(&mut self, id: i32, start: Instant) {
+        self.call_results.entry(id).or_default().record(start.elapsed());
+    }
+}
@@ -0,0 +1,147 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 4000;
+
+/// Access to the steam music interface
+pub struct Music<Manager> {
+    pub(crate) music: *mut sys::ISteamMusic,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+/// The playback state of steam music
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlaybackStatus {
+    Undefined,
+    Playing,
+    Paused,
+    Idle,
+}
+
+impl Into<sys::AudioPlayback_Status> for PlaybackStatus {
+    fn into(self) -> sys::AudioPlayback_Status {
+        match self {
+            PlaybackStatus::Undefined => sys::AudioPlayback_Status::AudioPlayback_Undefined,
+            PlaybackStatus::Playing => sys::AudioPlayback_Status::AudioPlayback_Playing,
+            PlaybackStatus::Paused => sys::AudioPlayback_Status::AudioPlayback_Paused,
+            PlaybackStatus::Idle => sys::AudioPlayback_Status::AudioPlayback_Idle,
+        }
+    }
+}
+
+impl <Manager> Music<Manager> {
+    /// Returns the raw `ISteamMusic` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamMusic {
+        self.music
+    }
+
+    /// Returns whether steam music is enabled
+    pub fn is_enabled(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusic_BIsEnabled(self.music)
+        }
+    }
+
+    /// Returns whether a song is currently playing
+    pub fn is_playing(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusic_BIsPlaying(self.music)
+        }
+    }
+
+    /// Returns the current playback status
+    pub fn playback_status(&self) -> PlaybackStatus {
+        unsafe {
+            match sys::SteamAPI_ISteamMusic_GetPlaybackStatus(self.music) {
+                sys::AudioPlayback_Status::AudioPlayback_Playing => PlaybackStatus::Playing,
+                sys::AudioPlayback_Status::AudioPlayback_Paused => PlaybackStatus::Paused,
+                sys::AudioPlayback_Status::AudioPlayback_Idle => PlaybackStatus::Idle,
+                _ => PlaybackStatus::Undefined,
+            }
+        }
+    }
+
+    /// Starts or resumes playback
+    pub fn play(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamMusic_Play(self.music);
+        }
+    }
+
+    /// Pauses playback
+    pub fn pause(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamMusic_Pause(self.music);
+        }
+    }
+
+    /// Skips to the previous song
+    pub fn play_previous(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamMusic_PlayPrevious(self.music);
+        }
+    }
+
+    /// Skips to the next song
+    pub fn play_next(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamMusic_PlayNext(self.music);
+        }
+    }
+
+    /// Sets the playback volume between `0.0` and `1.0`
+    pub fn set_volume(&self, volume: f32) {
+        unsafe {
+            sys::SteamAPI_ISteamMusic_SetVolume(self.music, volume);
+        }
+    }
+
+    /// Returns the playback volume between `0.0` and `1.0`
+    pub fn volume(&self) -> f32 {
+        unsafe {
+            sys::SteamAPI_ISteamMusic_GetVolume(self.music)
+        }
+    }
+}
+
+/// Called when the playback status of steam music changes.
+///
+/// The new status can be read with `Music::playback_status`.
+#[derive(Clone, Debug)]
+pub struct PlaybackStatusHasChanged;
+
+unsafe impl Callback for PlaybackStatusHasChanged {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::PlaybackStatusHasChanged_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        PlaybackStatusHasChanged
+    }
+}
+
+/// Called when the volume of steam music changes
+#[derive(Clone, Debug)]
+pub struct VolumeHasChanged {
+    /// The new volume between `0.0` and `1.0`
+    pub new_volume: f32,
+}
+
+unsafe impl Callback for VolumeHasChanged {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::VolumeHasChanged_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::VolumeHasChanged_t);
+        VolumeHasChanged {
+            new_volume: val.m_flNewVolume,
+        }
+    }
+}
@@ -0,0 +1,458 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 4100;
+// A few of the remote's callbacks share ids with the `ISteamMusic` range
+const MUSIC_CALLBACK_BASE_ID: i32 = 4000;
+
+/// Access to the steam music remote interface
+///
+/// Allows an application to act as the source that steam music
+/// is controlled through.
+pub struct MusicRemote<Manager> {
+    pub(crate) music_remote: *mut sys::ISteamMusicRemote,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+impl <Manager> MusicRemote<Manager> {
+    /// Returns the raw `ISteamMusicRemote` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamMusicRemote {
+        self.music_remote
+    }
+
+    /// Registers the application as a steam music remote with the
+    /// given name.
+    ///
+    /// Returns false if another remote is already registered.
+    pub fn register(&self, name: &str) -> bool {
+        let name = CString::new(name).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_RegisterSteamMusicRemote(self.music_remote, name.as_ptr())
+        }
+    }
+
+    /// Deregisters the application as a steam music remote
+    pub fn deregister(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_DeregisterSteamMusicRemote(self.music_remote)
+        }
+    }
+
+    /// Returns whether this application is the currently active remote
+    pub fn is_current_music_remote(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_BIsCurrentMusicRemote(self.music_remote)
+        }
+    }
+
+    /// Reports whether activating the remote, in response to a
+    /// `MusicPlayerRemoteWillActivate` callback, succeeded
+    pub fn activation_success(&self, success: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_BActivationSuccess(self.music_remote, success)
+        }
+    }
+
+    /// Sets the name shown for the remote in steam
+    pub fn set_display_name(&self, name: &str) -> bool {
+        let name = CString::new(name).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_SetDisplayName(self.music_remote, name.as_ptr())
+        }
+    }
+
+    /// Sets the icon shown for the remote in steam from a 64x64
+    /// PNG image
+    pub fn set_png_icon_64x64(&self, png: &[u8]) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_SetPNGIcon_64x64(
+                self.music_remote,
+                png.as_ptr() as *mut _, png.len() as _
+            )
+        }
+    }
+
+    /// Sets whether the remote supports skipping to the previous entry
+    pub fn enable_play_previous(&self, enable: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_EnablePlayPrevious(self.music_remote, enable)
+        }
+    }
+
+    /// Sets whether the remote supports skipping to the next entry
+    pub fn enable_play_next(&self, enable: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_EnablePlayNext(self.music_remote, enable)
+        }
+    }
+
+    /// Sets whether the remote supports shuffling
+    pub fn enable_shuffled(&self, enable: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_EnableShuffled(self.music_remote, enable)
+        }
+    }
+
+    /// Sets whether the remote supports looping
+    pub fn enable_looped(&self, enable: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_EnableLooped(self.music_remote, enable)
+        }
+    }
+
+    /// Sets whether the remote provides a play queue
+    pub fn enable_queue(&self, enable: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_EnableQueue(self.music_remote, enable)
+        }
+    }
+
+    /// Sets whether the remote provides playlists
+    pub fn enable_playlists(&self, enable: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_EnablePlaylists(self.music_remote, enable)
+        }
+    }
+
+    /// Updates the playback status shown by steam
+    pub fn update_playback_status(&self, status: PlaybackStatus) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_UpdatePlaybackStatus(self.music_remote, status.into())
+        }
+    }
+
+    /// Updates whether playback is shuffled
+    pub fn update_shuffled(&self, shuffled: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_UpdateShuffled(self.music_remote, shuffled)
+        }
+    }
+
+    /// Updates whether playback is looped
+    pub fn update_looped(&self, looped: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_UpdateLooped(self.music_remote, looped)
+        }
+    }
+
+    /// Updates the volume shown by steam, between `0.0` and `1.0`
+    pub fn update_volume(&self, volume: f32) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_UpdateVolume(self.music_remote, volume)
+        }
+    }
+
+    /// Begins an update of the current entry.
+    ///
+    /// Must be followed by `current_entry_did_change` once the entry
+    /// has been updated.
+    pub fn current_entry_will_change(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_CurrentEntryWillChange(self.music_remote)
+        }
+    }
+
+    /// Sets whether there is a current entry
+    pub fn current_entry_is_available(&self, available: bool) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_CurrentEntryIsAvailable(self.music_remote, available)
+        }
+    }
+
+    /// Sets the text shown for the current entry
+    pub fn update_current_entry_text(&self, text: &str) -> bool {
+        let text = CString::new(text).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_UpdateCurrentEntryText(self.music_remote, text.as_ptr())
+        }
+    }
+
+    /// Sets how far into the current entry playback is
+    pub fn update_current_entry_elapsed_seconds(&self, seconds: i32) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_UpdateCurrentEntryElapsedSeconds(self.music_remote, seconds)
+        }
+    }
+
+    /// Sets the cover art of the current entry from a PNG or JPEG image
+    pub fn update_current_entry_cover_art(&self, image: &[u8]) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_UpdateCurrentEntryCoverArt(
+                self.music_remote,
+                image.as_ptr() as *mut _, image.len() as _
+            )
+        }
+    }
+
+    /// Finishes an update of the current entry
+    pub fn current_entry_did_change(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_CurrentEntryDidChange(self.music_remote)
+        }
+    }
+
+    /// Begins an update of the play queue.
+    ///
+    /// Must be followed by `queue_did_change` once the queue has
+    /// been updated.
+    pub fn queue_will_change(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_QueueWillChange(self.music_remote)
+        }
+    }
+
+    /// Removes all entries from the play queue
+    pub fn reset_queue_entries(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_ResetQueueEntries(self.music_remote)
+        }
+    }
+
+    /// Adds or replaces an entry in the play queue.
+    ///
+    /// The id is reported back in `MusicPlayerSelectsQueueEntry` when
+    /// the user selects the entry.
+    pub fn set_queue_entry(&self, id: i32, position: i32, text: &str) -> bool {
+        let text = CString::new(text).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_SetQueueEntry(self.music_remote, id, position, text.as_ptr())
+        }
+    }
+
+    /// Marks the queue entry with the given id as currently playing
+    pub fn set_current_queue_entry(&self, id: i32) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_SetCurrentQueueEntry(self.music_remote, id)
+        }
+    }
+
+    /// Finishes an update of the play queue
+    pub fn queue_did_change(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_QueueDidChange(self.music_remote)
+        }
+    }
+
+    /// Begins an update of the playlists.
+    ///
+    /// Must be followed by `playlist_did_change` once the playlists
+    /// have been updated.
+    pub fn playlist_will_change(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_PlaylistWillChange(self.music_remote)
+        }
+    }
+
+    /// Removes all playlists
+    pub fn reset_playlist_entries(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_ResetPlaylistEntries(self.music_remote)
+        }
+    }
+
+    /// Adds or replaces a playlist.
+    ///
+    /// The id is reported back in `MusicPlayerSelectsPlaylistEntry`
+    /// when the user selects the playlist.
+    pub fn set_playlist_entry(&self, id: i32, position: i32, text: &str) -> bool {
+        let text = CString::new(text).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_SetPlaylistEntry(self.music_remote, id, position, text.as_ptr())
+        }
+    }
+
+    /// Marks the playlist with the given id as currently playing
+    pub fn set_current_playlist_entry(&self, id: i32) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_SetCurrentPlaylistEntry(self.music_remote, id)
+        }
+    }
+
+    /// Finishes an update of the playlists
+    pub fn playlist_did_change(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamMusicRemote_PlaylistDidChange(self.music_remote)
+        }
+    }
+}
+
+/// Called when steam is about to make this application the active
+/// music remote.
+///
+/// The application should reply with `MusicRemote::activation_success`.
+#[derive(Clone, Debug)]
+pub struct MusicPlayerRemoteWillActivate;
+
+unsafe impl Callback for MusicPlayerRemoteWillActivate {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerRemoteWillActivate_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        MusicPlayerRemoteWillActivate
+    }
+}
+
+/// Called when this application stops being the active music remote
+#[derive(Clone, Debug)]
+pub struct MusicPlayerRemoteWillDeactivate;
+
+unsafe impl Callback for MusicPlayerRemoteWillDeactivate {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerRemoteWillDeactivate_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        MusicPlayerRemoteWillDeactivate
+    }
+}
+
+/// Called when the user wants playback to start or resume
+#[derive(Clone, Debug)]
+pub struct MusicPlayerWantsPlay;
+
+unsafe impl Callback for MusicPlayerWantsPlay {
+    const ID: i32 = CALLBACK_BASE_ID + 5;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerWantsPlay_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        MusicPlayerWantsPlay
+    }
+}
+
+/// Called when the user wants playback to pause
+#[derive(Clone, Debug)]
+pub struct MusicPlayerWantsPause;
+
+unsafe impl Callback for MusicPlayerWantsPause {
+    const ID: i32 = CALLBACK_BASE_ID + 6;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerWantsPause_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        MusicPlayerWantsPause
+    }
+}
+
+/// Called when the user wants to skip to the previous entry
+#[derive(Clone, Debug)]
+pub struct MusicPlayerWantsPlayPrevious;
+
+unsafe impl Callback for MusicPlayerWantsPlayPrevious {
+    const ID: i32 = CALLBACK_BASE_ID + 7;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerWantsPlayPrevious_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        MusicPlayerWantsPlayPrevious
+    }
+}
+
+/// Called when the user wants to skip to the next entry
+#[derive(Clone, Debug)]
+pub struct MusicPlayerWantsPlayNext;
+
+unsafe impl Callback for MusicPlayerWantsPlayNext {
+    const ID: i32 = CALLBACK_BASE_ID + 8;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerWantsPlayNext_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        MusicPlayerWantsPlayNext
+    }
+}
+
+/// Called when the user toggles shuffling
+#[derive(Clone, Debug)]
+pub struct MusicPlayerWantsShuffled {
+    pub shuffled: bool,
+}
+
+unsafe impl Callback for MusicPlayerWantsShuffled {
+    const ID: i32 = CALLBACK_BASE_ID + 9;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerWantsShuffled_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::MusicPlayerWantsShuffled_t);
+        MusicPlayerWantsShuffled {
+            shuffled: val.m_bShuffled,
+        }
+    }
+}
+
+/// Called when the user toggles looping
+#[derive(Clone, Debug)]
+pub struct MusicPlayerWantsLooped {
+    pub looped: bool,
+}
+
+unsafe impl Callback for MusicPlayerWantsLooped {
+    const ID: i32 = CALLBACK_BASE_ID + 10;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerWantsLooped_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::MusicPlayerWantsLooped_t);
+        MusicPlayerWantsLooped {
+            looped: val.m_bLooped,
+        }
+    }
+}
+
+/// Called when the user changes the volume
+#[derive(Clone, Debug)]
+pub struct MusicPlayerWantsVolume {
+    /// The requested volume between `0.0` and `1.0`
+    pub new_volume: f32,
+}
+
+unsafe impl Callback for MusicPlayerWantsVolume {
+    const ID: i32 = MUSIC_CALLBACK_BASE_ID + 11;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerWantsVolume_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::MusicPlayerWantsVolume_t);
+        MusicPlayerWantsVolume {
+            new_volume: val.m_flNewVolume,
+        }
+    }
+}
+
+/// Called when the user selects an entry in the play queue
+#[derive(Clone, Debug)]
+pub struct MusicPlayerSelectsQueueEntry {
+    /// The id passed to `MusicRemote::set_queue_entry`
+    pub id: i32,
+}
+
+unsafe impl Callback for MusicPlayerSelectsQueueEntry {
+    const ID: i32 = MUSIC_CALLBACK_BASE_ID + 12;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerSelectsQueueEntry_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::MusicPlayerSelectsQueueEntry_t);
+        MusicPlayerSelectsQueueEntry {
+            id: val.nID,
+        }
+    }
+}
+
+/// Called when the user selects a playlist
+#[derive(Clone, Debug)]
+pub struct MusicPlayerSelectsPlaylistEntry {
+    /// The id passed to `MusicRemote::set_playlist_entry`
+    pub id: i32,
+}
+
+unsafe impl Callback for MusicPlayerSelectsPlaylistEntry {
+    const ID: i32 = MUSIC_CALLBACK_BASE_ID + 13;
+    const SIZE: i32 = ::std::mem::size_of::<sys::MusicPlayerSelectsPlaylistEntry_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::MusicPlayerSelectsPlaylistEntry_t);
+        MusicPlayerSelectsPlaylistEntry {
+            id: val.nID,
+        }
+    }
+}
@@ -27,6 +27,19 @@ pub enum SendType {
 }
 
 impl <Manager> Networking<Manager> {
+    /// Returns the raw `ISteamNetworking` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamNetworking {
+        self.net
+    }
+
     /// Accepts incoming packets from the given user
     ///
     /// Should only be called in response to a `P2PSessionRequest`.
@@ -46,6 +59,13 @@ impl <Manager> Networking<Manager> {
     /// Sends a packet to the start user starting the
     /// connection if it isn't started already
     pub fn send_p2p_packet(&self, remote: SteamId, send_type: SendType, data: &[u8]) -> bool {
+        self.send_p2p_packet_on_channel(remote, send_type, data, 0)
+    }
+
+    /// Like `send_p2p_packet` but on the given channel.
+    ///
+    /// Packets on other channels are read with `read_p2p_packet_from_channel`.
+    pub fn send_p2p_packet_on_channel(&self, remote: SteamId, send_type: SendType, data: &[u8], channel: i32) -> bool {
         unsafe {
             let send_type = match send_type {
                 SendType::Unreliable => sys::EP2PSend::k_EP2PSendUnreliable,
@@ -53,7 +73,7 @@ impl <Manager> Networking<Manager> {
                 SendType::Reliable => sys::EP2PSend::k_EP2PSendReliable,
                 SendType::ReliableWithBuffering => sys::EP2PSend::k_EP2PSendReliableWithBuffering,
             };
-            sys::SteamAPI_ISteamNetworking_SendP2PPacket(self.net, remote.0, data.as_ptr() as *const _, data.len() as u32, send_type, 0)
+            sys::SteamAPI_ISteamNetworking_SendP2PPacket(self.net, remote.0, data.as_ptr() as *const _, data.len() as u32, send_type, channel)
         }
     }
 
@@ -61,9 +81,14 @@ impl <Manager> Networking<Manager> {
     ///
     /// Returns the size of the queued packet if any.
     pub fn is_p2p_packet_available(&self) -> Option<usize> {
+        self.is_p2p_packet_available_on_channel(0)
+    }
+
+    /// Like `is_p2p_packet_available` but for the given channel
+    pub fn is_p2p_packet_available_on_channel(&self, channel: i32) -> Option<usize> {
         unsafe {
             let mut size = 0;
-            if sys::SteamAPI_ISteamNetworking_IsP2PPacketAvailable(self.net, &mut size, 0) {
+            if sys::SteamAPI_ISteamNetworking_IsP2PPacketAvailable(self.net, &mut size, channel) {
                 Some(size as usize)
             } else {
                 None
@@ -77,16 +102,89 @@ impl <Manager> Networking<Manager> {
     /// Returns the steam id of the sender and the size of the
     /// packet.
     pub fn read_p2p_packet(&self, buf: &mut [u8]) -> Option<(SteamId, usize)> {
+        self.read_p2p_packet_from_channel(buf, 0)
+    }
+
+    /// Like `read_p2p_packet` but for the given channel
+    pub fn read_p2p_packet_from_channel(&self, buf: &mut [u8], channel: i32) -> Option<(SteamId, usize)> {
         unsafe {
             let mut size = 0;
             let mut remote = 0;
-            if sys::SteamAPI_ISteamNetworking_ReadP2PPacket(self.net, buf.as_mut_ptr() as *mut _, buf.len() as _, &mut size, &mut remote as *mut _ as *mut _, 0) {
+            if sys::SteamAPI_ISteamNetworking_ReadP2PPacket(self.net, buf.as_mut_ptr() as *mut _, buf.len() as _, &mut size, &mut remote as *mut _ as *mut _, channel) {
                 Some((SteamId(remote), size as usize))
             } else {
                 None
             }
         }
     }
+
+    /// Reads up to `slots.len()` queued packets into the passed slots.
+    ///
+    /// The slots' buffers are reused between calls and are only
+    /// reallocated when a packet larger than any previously read by
+    /// that slot arrives.
+    ///
+    /// Returns the number of slots that were filled.
+    pub fn read_p2p_packets(&self, slots: &mut [P2PPacketSlot]) -> usize {
+        let mut count = 0;
+        for slot in slots {
+            let size = match self.is_p2p_packet_available() {
+                Some(size) => size,
+                None => break,
+            };
+            if slot.buf.len() < size {
+                slot.buf.resize(size, 0);
+            }
+            match self.read_p2p_packet(&mut slot.buf) {
+                Some((remote, len)) => {
+                    slot.remote = remote;
+                    slot.len = len;
+                    count += 1;
+                }
+                None => break,
+            }
+        }
+        count
+    }
+}
+
+/// A reusable buffer that a packet can be read into with
+/// `Networking::read_p2p_packets`
+#[derive(Clone, Debug)]
+pub struct P2PPacketSlot {
+    buf: Vec<u8>,
+    len: usize,
+    remote: SteamId,
+}
+
+impl P2PPacketSlot {
+    /// Creates a slot with a buffer of the given size.
+    ///
+    /// Allocating slots large enough for the expected packets up
+    /// front avoids allocating while reading.
+    pub fn with_capacity(capacity: usize) -> P2PPacketSlot {
+        P2PPacketSlot {
+            buf: vec![0; capacity],
+            len: 0,
+            remote: SteamId(0),
+        }
+    }
+
+    /// Returns the contents of the last packet read into the slot
+    pub fn data(&self) -> &[u8] {
+        &self.buf[.. self.len]
+    }
+
+    /// Returns the sender of the last packet read into the slot
+    pub fn remote(&self) -> SteamId {
+        self.remote
+    }
+}
+
+impl Default for P2PPacketSlot {
+    fn default() -> P2PPacketSlot {
+        P2PPacketSlot::with_capacity(0)
+    }
 }
 
 /// Called when a user wants to communicate via p2p
@@ -0,0 +1,737 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 1220;
+
+/// Access to the steam networking sockets interface
+pub struct NetworkingSockets<Manager> {
+    pub(crate) sockets: *mut sys::ISteamNetworkingSockets,
+    pub(crate) inner: Arc<Inner<Manager>>,
+}
+
+/// A handle to a connection made through `NetworkingSockets`
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NetConnection(pub(crate) sys::HSteamNetConnection);
+
+impl NetConnection {
+    /// Creates a `NetConnection` from a raw connection handle
+    pub fn from_raw(raw: u32) -> NetConnection {
+        NetConnection(raw)
+    }
+
+    /// Returns the raw connection handle
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+}
+
+/// A handle to a socket listening for connections, created
+/// through `NetworkingSockets`
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ListenSocket(pub(crate) sys::HSteamListenSocket);
+
+impl ListenSocket {
+    /// Creates a `ListenSocket` from a raw socket handle
+    pub fn from_raw(raw: u32) -> ListenSocket {
+        ListenSocket(raw)
+    }
+
+    /// Returns the raw socket handle
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+}
+
+/// The state of a connection
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NetworkingConnectionState {
+    /// The connection doesn't exist or has already been closed
+    None,
+    /// The connection is being established
+    Connecting,
+    /// A route to the peer is being found through the relay network
+    FindingRoute,
+    /// The connection is established
+    Connected,
+    /// The peer closed the connection
+    ClosedByPeer,
+    /// A problem was detected locally, such as a timeout
+    ProblemDetectedLocally,
+    /// The connection has been closed locally and is finishing up
+    FinWait,
+    /// The connection has been closed locally and is still sending
+    /// queued reliable messages
+    Linger,
+    /// The connection is completely closed
+    Dead,
+}
+
+impl From<sys::ESteamNetworkingConnectionState> for NetworkingConnectionState {
+    fn from(state: sys::ESteamNetworkingConnectionState) -> Self {
+        use sys::ESteamNetworkingConnectionState::*;
+        match state {
+            k_ESteamNetworkingConnectionState_Connecting => NetworkingConnectionState::Connecting,
+            k_ESteamNetworkingConnectionState_FindingRoute => NetworkingConnectionState::FindingRoute,
+            k_ESteamNetworkingConnectionState_Connected => NetworkingConnectionState::Connected,
+            k_ESteamNetworkingConnectionState_ClosedByPeer => NetworkingConnectionState::ClosedByPeer,
+            k_ESteamNetworkingConnectionState_ProblemDetectedLocally => NetworkingConnectionState::ProblemDetectedLocally,
+            k_ESteamNetworkingConnectionState_FinWait => NetworkingConnectionState::FinWait,
+            k_ESteamNetworkingConnectionState_Linger => NetworkingConnectionState::Linger,
+            k_ESteamNetworkingConnectionState_Dead => NetworkingConnectionState::Dead,
+            _ => NetworkingConnectionState::None,
+        }
+    }
+}
+
+/// The reason a connection was closed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NetConnectionEnd {
+    /// No reason was given
+    Invalid,
+    /// A normal close by the application with a code between
+    /// 1000 and 1999
+    App(i32),
+    /// An abnormal close by the application with a code between
+    /// 2000 and 2999
+    AppException(i32),
+    /// Steam is in offline mode
+    LocalOfflineMode,
+    /// Connections to the relay network are failing
+    LocalManyRelayConnectivity,
+    /// The hosted server can't reach its primary relay
+    LocalHostedServerPrimaryRelay,
+    /// The network config couldn't be fetched
+    LocalNetworkConfig,
+    /// The account doesn't have the rights to the connection
+    LocalRights,
+    /// The peer stopped responding
+    RemoteTimeout,
+    /// The peer's encryption was invalid
+    RemoteBadCrypt,
+    /// The peer's certificate was invalid
+    RemoteBadCert,
+    /// The peer isn't logged into steam
+    RemoteNotLoggedIn,
+    /// The peer isn't running the app
+    RemoteNotRunningApp,
+    /// The peer is using an incompatible protocol version
+    RemoteBadProtocolVersion,
+    /// A generic failure
+    MiscGeneric,
+    /// An internal error in steam
+    MiscInternalError,
+    /// The connection timed out
+    MiscTimeout,
+    /// The relay network couldn't be reached
+    MiscRelayConnectivity,
+    /// Steam couldn't be reached
+    MiscSteamConnectivity,
+    /// No relay sessions to the client are available
+    MiscNoRelaySessionsToClient,
+    /// A reason not known to this crate
+    Other(i32),
+}
+
+impl NetConnectionEnd {
+    /// Creates a `NetConnectionEnd` from a raw reason code
+    pub fn from_raw(code: i32) -> NetConnectionEnd {
+        match code {
+            0 => NetConnectionEnd::Invalid,
+            1000 ..= 1999 => NetConnectionEnd::App(code),
+            2000 ..= 2999 => NetConnectionEnd::AppException(code),
+            3001 => NetConnectionEnd::LocalOfflineMode,
+            3002 => NetConnectionEnd::LocalManyRelayConnectivity,
+            3003 => NetConnectionEnd::LocalHostedServerPrimaryRelay,
+            3004 => NetConnectionEnd::LocalNetworkConfig,
+            3005 => NetConnectionEnd::LocalRights,
+            4001 => NetConnectionEnd::RemoteTimeout,
+            4002 => NetConnectionEnd::RemoteBadCrypt,
+            4003 => NetConnectionEnd::RemoteBadCert,
+            4004 => NetConnectionEnd::RemoteNotLoggedIn,
+            4005 => NetConnectionEnd::RemoteNotRunningApp,
+            4006 => NetConnectionEnd::RemoteBadProtocolVersion,
+            5001 => NetConnectionEnd::MiscGeneric,
+            5002 => NetConnectionEnd::MiscInternalError,
+            5003 => NetConnectionEnd::MiscTimeout,
+            5004 => NetConnectionEnd::MiscRelayConnectivity,
+            5005 => NetConnectionEnd::MiscSteamConnectivity,
+            5006 => NetConnectionEnd::MiscNoRelaySessionsToClient,
+            _ => NetConnectionEnd::Other(code),
+        }
+    }
+
+    /// Returns the raw reason code
+    pub fn raw(&self) -> i32 {
+        match *self {
+            NetConnectionEnd::Invalid => 0,
+            NetConnectionEnd::App(code) => code,
+            NetConnectionEnd::AppException(code) => code,
+            NetConnectionEnd::LocalOfflineMode => 3001,
+            NetConnectionEnd::LocalManyRelayConnectivity => 3002,
+            NetConnectionEnd::LocalHostedServerPrimaryRelay => 3003,
+            NetConnectionEnd::LocalNetworkConfig => 3004,
+            NetConnectionEnd::LocalRights => 3005,
+            NetConnectionEnd::RemoteTimeout => 4001,
+            NetConnectionEnd::RemoteBadCrypt => 4002,
+            NetConnectionEnd::RemoteBadCert => 4003,
+            NetConnectionEnd::RemoteNotLoggedIn => 4004,
+            NetConnectionEnd::RemoteNotRunningApp => 4005,
+            NetConnectionEnd::RemoteBadProtocolVersion => 4006,
+            NetConnectionEnd::MiscGeneric => 5001,
+            NetConnectionEnd::MiscInternalError => 5002,
+            NetConnectionEnd::MiscTimeout => 5003,
+            NetConnectionEnd::MiscRelayConnectivity => 5004,
+            NetConnectionEnd::MiscSteamConnectivity => 5005,
+            NetConnectionEnd::MiscNoRelaySessionsToClient => 5006,
+            NetConnectionEnd::Other(code) => code,
+        }
+    }
+}
+
+/// Information about a connection
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NetConnectionInfo {
+    /// The current state of the connection
+    pub state: NetworkingConnectionState,
+    /// Why the connection was closed, if it was
+    pub end_reason: NetConnectionEnd,
+    /// The debug message given when the connection was closed
+    pub end_debug: String,
+    /// A human readable description of the connection
+    pub description: String,
+    /// The user data set on the connection
+    pub user_data: i64,
+    /// The identity of the remote peer, if known
+    pub remote_identity: NetworkingIdentity,
+    /// The address of the remote peer, if connected by ip
+    pub remote_address: NetworkingIpAddr,
+    /// The listen socket that accepted the connection, or `None` if
+    /// the connection was started locally
+    pub listen_socket: Option<ListenSocket>,
+}
+
+impl NetConnectionInfo {
+    unsafe fn from_sys(info: &sys::SteamNetConnectionInfo_t) -> NetConnectionInfo {
+        NetConnectionInfo {
+            state: info.m_eState.into(),
+            end_reason: NetConnectionEnd::from_raw(info.m_eEndReason),
+            end_debug: CStr::from_ptr(info.m_szEndDebug.as_ptr()).to_string_lossy().into_owned(),
+            description: CStr::from_ptr(info.m_szConnectionDescription.as_ptr()).to_string_lossy().into_owned(),
+            user_data: info.m_nUserData,
+            remote_identity: NetworkingIdentity::from_sys(&{ info.m_identityRemote }),
+            remote_address: NetworkingIpAddr::from_sys(&{ info.m_addrRemote }),
+            listen_socket: if info.m_hListenSocket == sys::k_HSteamListenSocket_Invalid {
+                None
+            } else {
+                Some(ListenSocket(info.m_hListenSocket))
+            },
+        }
+    }
+}
+
+bitflags! {
+    /// Controls how a message is sent
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[repr(C)]
+    pub struct SendFlags: i32 {
+        /// Send the message unreliably, it may be lost or arrive
+        /// out of order
+        const UNRELIABLE = sys::k_nSteamNetworkingSend_Unreliable;
+        /// Send the message immediately instead of waiting to
+        /// combine it with other messages
+        const NO_NAGLE = sys::k_nSteamNetworkingSend_NoNagle;
+        /// Drop the message if it can't be sent right away
+        const NO_DELAY = sys::k_nSteamNetworkingSend_NoDelay;
+        /// Send the message reliably and in order
+        const RELIABLE = sys::k_nSteamNetworkingSend_Reliable;
+        /// Do the sending work on the calling thread instead of
+        /// the service thread
+        const USE_CURRENT_THREAD = sys::k_nSteamNetworkingSend_UseCurrentThread;
+    }
+}
+
+/// A message sent or received through `NetworkingSockets`.
+///
+/// Received messages are returned to steam when dropped.
+pub struct NetworkingMessage {
+    pub(crate) message: *mut sys::SteamNetworkingMessage_t,
+}
+
+unsafe impl Send for NetworkingMessage {}
+unsafe impl Sync for NetworkingMessage {}
+
+impl NetworkingMessage {
+    /// Returns the payload of the message
+    pub fn data(&self) -> &[u8] {
+        unsafe {
+            let message = &*self.message;
+            if message.m_pData.is_null() {
+                return &[];
+            }
+            std::slice::from_raw_parts(message.m_pData as *const u8, message.m_cbSize as usize)
+        }
+    }
+
+    /// Returns the payload of the message for writing
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            let message = &mut *self.message;
+            if message.m_pData.is_null() {
+                return &mut [];
+            }
+            std::slice::from_raw_parts_mut(message.m_pData as *mut u8, message.m_cbSize as usize)
+        }
+    }
+
+    /// Returns the connection the message was received on or
+    /// will be sent to
+    pub fn connection(&self) -> NetConnection {
+        unsafe { NetConnection((*self.message).m_conn) }
+    }
+
+    /// Sets the connection the message will be sent to
+    pub fn set_connection(&mut self, connection: NetConnection) {
+        unsafe { (*self.message).m_conn = connection.0; }
+    }
+
+    /// Returns the flags the message was sent with
+    pub fn send_flags(&self) -> SendFlags {
+        unsafe { SendFlags::from_bits_truncate((*self.message).m_nFlags) }
+    }
+
+    /// Sets the flags the message will be sent with
+    pub fn set_send_flags(&mut self, flags: SendFlags) {
+        unsafe { (*self.message).m_nFlags = flags.bits(); }
+    }
+
+    /// Returns the identity of the peer that sent the message
+    pub fn identity_peer(&self) -> NetworkingIdentity {
+        unsafe { NetworkingIdentity::from_sys(&(*self.message).m_identityPeer) }
+    }
+
+    /// Returns the lane the message was sent on
+    pub fn channel(&self) -> i32 {
+        unsafe { (*self.message).m_nChannel }
+    }
+
+    /// Returns the message number assigned by the sender
+    pub fn message_number(&self) -> i64 {
+        unsafe { (*self.message).m_nMessageNumber }
+    }
+
+    /// Returns the user data of the connection at the time the
+    /// message was received
+    pub fn connection_user_data(&self) -> i64 {
+        unsafe { (*self.message).m_nConnUserData }
+    }
+
+    /// Returns when the message was received, on the clock of
+    /// `NetworkingUtils::local_timestamp`
+    pub fn time_received(&self) -> i64 {
+        unsafe { (*self.message).m_usecTimeReceived }
+    }
+
+    /// Returns the raw message pointer
+    ///
+    /// # Safety
+    ///
+    /// The message is released when this is dropped, so the pointer
+    /// must not be used after that and must not be released by hand.
+    pub unsafe fn as_raw(&self) -> *mut sys::SteamNetworkingMessage_t {
+        self.message
+    }
+
+    fn into_raw(self) -> *mut sys::SteamNetworkingMessage_t {
+        let message = self.message;
+        std::mem::forget(self);
+        message
+    }
+}
+
+impl Drop for NetworkingMessage {
+    fn drop(&mut self) {
+        unsafe {
+            sys::SteamAPI_SteamNetworkingMessage_t_Release(self.message);
+        }
+    }
+}
+
+impl <Manager> NetworkingSockets<Manager> {
+    /// Returns the raw `ISteamNetworkingSockets` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamNetworkingSockets {
+        self.sockets
+    }
+
+    /// Starts fetching the certificate needed for authenticated
+    /// connections.
+    ///
+    /// This happens automatically when a connection needs it but
+    /// starting early avoids delaying the first connection.
+    pub fn init_authentication(&self) -> NetworkingAvailability {
+        unsafe {
+            sys::SteamAPI_ISteamNetworkingSockets_InitAuthentication(self.sockets).into()
+        }
+    }
+
+    /// Returns whether the certificate needed for authenticated
+    /// connections is ready
+    pub fn authentication_status(&self) -> NetworkingAvailability {
+        unsafe {
+            sys::SteamAPI_ISteamNetworkingSockets_GetAuthenticationStatus(self.sockets, std::ptr::null_mut()).into()
+        }
+    }
+
+    /// Sends a message on the connection.
+    ///
+    /// Returns the message number assigned to the message.
+    pub fn send_message(&self, connection: NetConnection, data: &[u8], flags: SendFlags) -> SResult<i64> {
+        unsafe {
+            let mut message_number = 0;
+            let res = sys::SteamAPI_ISteamNetworkingSockets_SendMessageToConnection(
+                self.sockets, connection.0,
+                data.as_ptr() as *const _, data.len() as u32,
+                flags.bits(), &mut message_number,
+            );
+            if res == sys::EResult::k_EResultOK {
+                Ok(message_number)
+            } else {
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Sends a batch of messages in a single call.
+    ///
+    /// The messages should be allocated with `NetworkingUtils::allocate_message`
+    /// and have their connection and flags set. The results are returned
+    /// in the same order as the messages, either the assigned message
+    /// number or the reason the message couldn't be sent.
+    pub fn send_messages(&self, messages: Vec<NetworkingMessage>) -> Vec<SResult<i64>> {
+        unsafe {
+            let raw = messages.into_iter()
+                .map(NetworkingMessage::into_raw)
+                .collect::<Vec<_>>();
+            let mut results = vec![0i64; raw.len()];
+            sys::SteamAPI_ISteamNetworkingSockets_SendMessages(
+                self.sockets, raw.len() as _,
+                raw.as_ptr(), results.as_mut_ptr(),
+            );
+            results.into_iter()
+                .map(|v| if v >= 0 { Ok(v) } else { Err(steam_error_from_raw(-v)) })
+                .collect()
+        }
+    }
+
+    /// Starts connecting to a server by its ip address.
+    ///
+    /// Returns `None` if the connection couldn't be started. The
+    /// progress of the connection is reported through
+    /// `NetConnectionStatusChanged`.
+    pub fn connect_by_ip_address(&self, address: &NetworkingIpAddr) -> Option<NetConnection> {
+        self.connect_by_ip_address_with_config(address, &NetworkingConfig::new())
+    }
+
+    /// Like `connect_by_ip_address` but applies the config to the
+    /// connection before it starts
+    pub fn connect_by_ip_address_with_config(&self, address: &NetworkingIpAddr, config: &NetworkingConfig) -> Option<NetConnection> {
+        unsafe {
+            let address = address.to_sys();
+            let options = config.to_sys();
+            let conn = sys::SteamAPI_ISteamNetworkingSockets_ConnectByIPAddress(
+                self.sockets, &address,
+                options.len() as _, options.as_ptr(),
+            );
+            if conn == sys::k_HSteamNetConnection_Invalid {
+                None
+            } else {
+                Some(NetConnection(conn))
+            }
+        }
+    }
+
+    /// Starts connecting to a peer through steam's relay network
+    /// using the given virtual port.
+    ///
+    /// Returns `None` if the connection couldn't be started.
+    pub fn connect_p2p(&self, identity: &NetworkingIdentity, virtual_port: i32) -> Option<NetConnection> {
+        self.connect_p2p_with_config(identity, virtual_port, &NetworkingConfig::new())
+    }
+
+    /// Like `connect_p2p` but applies the config to the connection
+    /// before it starts
+    pub fn connect_p2p_with_config(&self, identity: &NetworkingIdentity, virtual_port: i32, config: &NetworkingConfig) -> Option<NetConnection> {
+        unsafe {
+            let identity = identity.to_sys();
+            let options = config.to_sys();
+            let conn = sys::SteamAPI_ISteamNetworkingSockets_ConnectP2P(
+                self.sockets, &identity, virtual_port,
+                options.len() as _, options.as_ptr(),
+            );
+            if conn == sys::k_HSteamNetConnection_Invalid {
+                None
+            } else {
+                Some(NetConnection(conn))
+            }
+        }
+    }
+
+    /// Starts listening for ip connections on the address.
+    ///
+    /// The config is inherited by every connection accepted on the
+    /// socket. Incoming connections are reported through
+    /// `NetConnectionStatusChanged` and must be accepted with
+    /// `accept_connection`. Returns `None` if the socket couldn't be
+    /// created.
+    pub fn create_listen_socket_ip(&self, address: &NetworkingIpAddr, config: &NetworkingConfig) -> Option<ListenSocket> {
+        unsafe {
+            let address = address.to_sys();
+            let options = config.to_sys();
+            let socket = sys::SteamAPI_ISteamNetworkingSockets_CreateListenSocketIP(
+                self.sockets, &address,
+                options.len() as _, options.as_ptr(),
+            );
+            if socket == sys::k_HSteamListenSocket_Invalid {
+                None
+            } else {
+                Some(ListenSocket(socket))
+            }
+        }
+    }
+
+    /// Starts listening for p2p connections on the virtual port.
+    ///
+    /// See `create_listen_socket_ip`.
+    pub fn create_listen_socket_p2p(&self, virtual_port: i32, config: &NetworkingConfig) -> Option<ListenSocket> {
+        unsafe {
+            let options = config.to_sys();
+            let socket = sys::SteamAPI_ISteamNetworkingSockets_CreateListenSocketP2P(
+                self.sockets, virtual_port,
+                options.len() as _, options.as_ptr(),
+            );
+            if socket == sys::k_HSteamListenSocket_Invalid {
+                None
+            } else {
+                Some(ListenSocket(socket))
+            }
+        }
+    }
+
+    /// Stops listening and closes every connection accepted on
+    /// the socket.
+    ///
+    /// Returns false if the socket handle is invalid.
+    pub fn close_listen_socket(&self, socket: ListenSocket) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamNetworkingSockets_CloseListenSocket(self.sockets, socket.0)
+        }
+    }
+
+    /// Connects to a steam user over the relay network, retrying
+    /// and reconnecting automatically.
+    ///
+    /// Unlike `connect_p2p` the connection isn't started immediately,
+    /// `PeerConnection::poll` must be called regularly to drive it.
+    pub fn connect_to_peer(&self, peer: SteamId) -> PeerConnection<Manager> {
+        unsafe {
+            let utils = sys::SteamAPI_SteamNetworkingUtils_v003();
+            debug_assert!(!utils.is_null());
+            PeerConnection::new(
+                NetworkingSockets {
+                    sockets: self.sockets,
+                    inner: self.inner.clone(),
+                },
+                NetworkingUtils {
+                    utils,
+                    _inner: self.inner.clone(),
+                },
+                peer,
+            )
+        }
+    }
+
+    /// Accepts an incoming connection.
+    ///
+    /// This should be called in response to a `NetConnectionStatusChanged`
+    /// with the `Connecting` state on a listen socket.
+    pub fn accept_connection(&self, connection: NetConnection) -> SResult<()> {
+        unsafe {
+            let res = sys::SteamAPI_ISteamNetworkingSockets_AcceptConnection(self.sockets, connection.0);
+            if res == sys::EResult::k_EResultOK {
+                Ok(())
+            } else {
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Closes the connection.
+    ///
+    /// `reason` and `debug` are sent to the peer and show up in its
+    /// `NetConnectionStatusChanged`. If `linger` is set, queued
+    /// reliable messages are still sent before the connection closes.
+    pub fn close_connection(&self, connection: NetConnection, reason: NetConnectionEnd, debug: &str, linger: bool) -> bool {
+        unsafe {
+            let debug = CString::new(debug).unwrap();
+            sys::SteamAPI_ISteamNetworkingSockets_CloseConnection(
+                self.sockets, connection.0,
+                reason.raw(), debug.as_ptr(), linger,
+            )
+        }
+    }
+
+    /// Returns information about the connection.
+    ///
+    /// Returns `None` if the connection handle is invalid.
+    pub fn connection_info(&self, connection: NetConnection) -> Option<NetConnectionInfo> {
+        unsafe {
+            let mut info: sys::SteamNetConnectionInfo_t = std::mem::zeroed();
+            if sys::SteamAPI_ISteamNetworkingSockets_GetConnectionInfo(self.sockets, connection.0, &mut info) {
+                Some(NetConnectionInfo::from_sys(&info))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns a multi-line description of the connection's state
+    /// for debugging.
+    ///
+    /// Returns `None` if the connection handle is invalid.
+    pub fn detailed_connection_status(&self, connection: NetConnection) -> Option<String> {
+        unsafe {
+            let mut buffer = vec![0; 2048];
+            loop {
+                let res = sys::SteamAPI_ISteamNetworkingSockets_GetDetailedConnectionStatus(
+                    self.sockets, connection.0, buffer.as_mut_ptr(), buffer.len() as _,
+                );
+                if res < 0 {
+                    return None;
+                } else if res > 0 {
+                    // The buffer was too small, `res` is the size needed
+                    buffer.resize(res as usize, 0);
+                    continue;
+                }
+                return Some(CStr::from_ptr(buffer.as_ptr()).to_string_lossy().into_owned());
+            }
+        }
+    }
+
+    /// Sets a name for the connection, used in debug output
+    pub fn set_connection_name(&self, connection: NetConnection, name: &str) {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            sys::SteamAPI_ISteamNetworkingSockets_SetConnectionName(self.sockets, connection.0, name.as_ptr());
+        }
+    }
+
+    /// Returns the name of the connection.
+    ///
+    /// Returns `None` if the connection handle is invalid.
+    pub fn connection_name(&self, connection: NetConnection) -> Option<String> {
+        unsafe {
+            let mut buffer = vec![0; 128];
+            if sys::SteamAPI_ISteamNetworkingSockets_GetConnectionName(self.sockets, connection.0, buffer.as_mut_ptr(), buffer.len() as _) {
+                Some(CStr::from_ptr(buffer.as_ptr()).to_string_lossy().into_owned())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Attaches a value to the connection, e.g. the index of the
+    /// player using it.
+    ///
+    /// The value is returned by `NetworkingMessage::connection_user_data`
+    /// for messages received afterwards, which avoids looking the
+    /// connection up on the receive path. Returns false if the
+    /// connection handle is invalid.
+    pub fn set_connection_user_data(&self, connection: NetConnection, user_data: i64) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamNetworkingSockets_SetConnectionUserData(self.sockets, connection.0, user_data)
+        }
+    }
+
+    /// Returns the value attached to the connection with
+    /// `set_connection_user_data`.
+    ///
+    /// Returns `None` if the connection handle is invalid.
+    pub fn connection_user_data(&self, connection: NetConnection) -> Option<i64> {
+        unsafe {
+            let user_data = sys::SteamAPI_ISteamNetworkingSockets_GetConnectionUserData(self.sockets, connection.0);
+            // -1 is returned for invalid handles
+            if user_data == -1 && self.connection_info(connection).is_none() {
+                None
+            } else {
+                Some(user_data)
+            }
+        }
+    }
+
+    /// Sends any messages queued on the connection immediately
+    /// instead of waiting for the nagle timer
+    pub fn flush_messages(&self, connection: NetConnection) -> SResult<()> {
+        unsafe {
+            let res = sys::SteamAPI_ISteamNetworkingSockets_FlushMessagesOnConnection(self.sockets, connection.0);
+            if res == sys::EResult::k_EResultOK {
+                Ok(())
+            } else {
+                Err(res.into())
+            }
+        }
+    }
+
+    /// Receives up to `max_messages` pending messages on the connection
+    pub fn receive_messages_on_connection(&self, connection: NetConnection, max_messages: usize) -> Vec<NetworkingMessage> {
+        unsafe {
+            let mut raw = vec![std::ptr::null_mut(); max_messages];
+            let count = sys::SteamAPI_ISteamNetworkingSockets_ReceiveMessagesOnConnection(
+                self.sockets, connection.0,
+                raw.as_mut_ptr(), max_messages as _,
+            );
+            raw.truncate(count.max(0) as usize);
+            raw.into_iter()
+                .map(|message| NetworkingMessage { message })
+                .collect()
+        }
+    }
+}
+
+/// Called when the state of a connection changes, including when
+/// a new connection arrives on a listen socket
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NetConnectionStatusChanged {
+    /// The connection that changed
+    pub connection: NetConnection,
+    /// The new information about the connection
+    pub info: NetConnectionInfo,
+    /// The state the connection was in before
+    pub old_state: NetworkingConnectionState,
+}
+
+unsafe impl Callback for NetConnectionStatusChanged {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamNetConnectionStatusChangedCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamNetConnectionStatusChangedCallback_t);
+        let info = val.m_info;
+        NetConnectionStatusChanged {
+            connection: NetConnection(val.m_hConn),
+            info: NetConnectionInfo::from_sys(&info),
+            old_state: val.m_eOldState.into(),
+        }
+    }
+}
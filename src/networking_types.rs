@@ -0,0 +1,471 @@
+use super::*;
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::str::FromStr;
+
+/// An ip address and port as used by `NetworkingSockets`.
+///
+/// Formats and parses as `ip:port`, using `[ip]:port` for IPv6.
+/// A missing port is parsed as port 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NetworkingIpAddr {
+    addr: SocketAddr,
+}
+
+impl NetworkingIpAddr {
+    /// Creates an address from an ip and port
+    pub fn new(ip: IpAddr, port: u16) -> NetworkingIpAddr {
+        NetworkingIpAddr {
+            addr: SocketAddr::new(ip, port),
+        }
+    }
+
+    /// Creates an address that listens on all interfaces on the
+    /// given port
+    pub fn any(port: u16) -> NetworkingIpAddr {
+        NetworkingIpAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), port)
+    }
+
+    /// Creates the IPv6 localhost address on the given port
+    pub fn localhost(port: u16) -> NetworkingIpAddr {
+        NetworkingIpAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), port)
+    }
+
+    /// Returns the ip of the address
+    pub fn ip(&self) -> IpAddr {
+        self.addr.ip()
+    }
+
+    /// Returns the port of the address
+    pub fn port(&self) -> u16 {
+        self.addr.port()
+    }
+
+    /// Returns whether this is an IPv4 address
+    pub fn is_ipv4(&self) -> bool {
+        self.addr.is_ipv4()
+    }
+
+    /// Returns whether this is a localhost address
+    pub fn is_localhost(&self) -> bool {
+        self.addr.ip().is_loopback()
+    }
+
+    pub(crate) fn to_sys(self) -> sys::SteamNetworkingIPAddr {
+        let ip = match self.addr.ip() {
+            IpAddr::V4(ip) => ip.to_ipv6_mapped(),
+            IpAddr::V6(ip) => ip,
+        };
+        unsafe {
+            let mut raw: sys::SteamNetworkingIPAddr = std::mem::zeroed();
+            raw.__bindgen_anon_1.m_ipv6 = ip.octets();
+            raw.m_port = self.addr.port();
+            raw
+        }
+    }
+
+    pub(crate) fn from_sys(raw: &sys::SteamNetworkingIPAddr) -> NetworkingIpAddr {
+        let octets = unsafe { raw.__bindgen_anon_1.m_ipv6 };
+        let ip = if octets[..10].iter().all(|&v| v == 0) && octets[10] == 0xFF && octets[11] == 0xFF {
+            IpAddr::V4(Ipv4Addr::new(octets[12], octets[13], octets[14], octets[15]))
+        } else {
+            IpAddr::V6(Ipv6Addr::from(octets))
+        };
+        NetworkingIpAddr::new(ip, raw.m_port)
+    }
+}
+
+impl From<SocketAddr> for NetworkingIpAddr {
+    fn from(addr: SocketAddr) -> NetworkingIpAddr {
+        NetworkingIpAddr { addr }
+    }
+}
+
+impl From<NetworkingIpAddr> for SocketAddr {
+    fn from(addr: NetworkingIpAddr) -> SocketAddr {
+        addr.addr
+    }
+}
+
+impl fmt::Display for NetworkingIpAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.addr, f)
+    }
+}
+
+impl FromStr for NetworkingIpAddr {
+    type Err = InvalidNetworkingAddress;
+
+    fn from_str(s: &str) -> Result<NetworkingIpAddr, InvalidNetworkingAddress> {
+        let s = s.trim();
+        if let Ok(addr) = s.parse::<SocketAddr>() {
+            return Ok(addr.into());
+        }
+        let ip = s.trim_start_matches('[').trim_end_matches(']');
+        ip.parse::<IpAddr>()
+            .map(|ip| NetworkingIpAddr::new(ip, 0))
+            .map_err(|_| InvalidNetworkingAddress)
+    }
+}
+
+/// The identity of a peer in `NetworkingSockets`.
+///
+/// Formats and parses using the same strings as the sdk, for
+/// example `steamid:76561197960265729` or `ip:127.0.0.1:27015`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NetworkingIdentity {
+    /// No identity
+    Invalid,
+    /// A steam user
+    SteamId(SteamId),
+    /// A peer identified by its ip address
+    IpAddress(NetworkingIpAddr),
+    /// An application defined string, up to 31 bytes
+    GenericString(String),
+    /// Application defined bytes, up to 32 bytes
+    GenericBytes(Vec<u8>),
+}
+
+const MAX_GENERIC_STRING: usize = 31;
+const MAX_GENERIC_BYTES: usize = 32;
+
+impl NetworkingIdentity {
+    /// Creates the identity used for local connections
+    pub fn localhost() -> NetworkingIdentity {
+        NetworkingIdentity::IpAddress(NetworkingIpAddr::localhost(0))
+    }
+
+    /// Returns the steam id of the identity, if it is one
+    pub fn steam_id(&self) -> Option<SteamId> {
+        match *self {
+            NetworkingIdentity::SteamId(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the identity is valid.
+    ///
+    /// Generic values that are too long are treated as invalid.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            NetworkingIdentity::Invalid => false,
+            NetworkingIdentity::SteamId(_) | NetworkingIdentity::IpAddress(_) => true,
+            NetworkingIdentity::GenericString(s) => !s.is_empty() && s.len() <= MAX_GENERIC_STRING && !s.contains('\0'),
+            NetworkingIdentity::GenericBytes(b) => !b.is_empty() && b.len() <= MAX_GENERIC_BYTES,
+        }
+    }
+
+    pub(crate) fn to_sys(&self) -> sys::SteamNetworkingIdentity {
+        use sys::ESteamNetworkingIdentityType::*;
+        unsafe {
+            let mut raw: sys::SteamNetworkingIdentity = std::mem::zeroed();
+            raw.m_eType = k_ESteamNetworkingIdentityType_Invalid;
+            if !self.is_valid() {
+                return raw;
+            }
+            match self {
+                NetworkingIdentity::Invalid => {},
+                NetworkingIdentity::SteamId(id) => {
+                    raw.m_eType = k_ESteamNetworkingIdentityType_SteamID;
+                    raw.m_cbSize = 8;
+                    raw.__bindgen_anon_1.m_steamID64 = id.raw();
+                },
+                NetworkingIdentity::IpAddress(addr) => {
+                    raw.m_eType = k_ESteamNetworkingIdentityType_IPAddress;
+                    raw.m_cbSize = std::mem::size_of::<sys::SteamNetworkingIPAddr>() as _;
+                    raw.__bindgen_anon_1.m_ip = addr.to_sys();
+                },
+                NetworkingIdentity::GenericString(s) => {
+                    raw.m_eType = k_ESteamNetworkingIdentityType_GenericString;
+                    raw.m_cbSize = s.len() as i32 + 1;
+                    let mut buf = [0u8; MAX_GENERIC_BYTES];
+                    buf[..s.len()].copy_from_slice(s.as_bytes());
+                    raw.__bindgen_anon_1.m_genericBytes = buf;
+                },
+                NetworkingIdentity::GenericBytes(b) => {
+                    raw.m_eType = k_ESteamNetworkingIdentityType_GenericBytes;
+                    raw.m_cbSize = b.len() as i32;
+                    let mut buf = [0u8; MAX_GENERIC_BYTES];
+                    buf[..b.len()].copy_from_slice(b);
+                    raw.__bindgen_anon_1.m_genericBytes = buf;
+                },
+            }
+            raw
+        }
+    }
+
+    pub(crate) fn from_sys(raw: &sys::SteamNetworkingIdentity) -> NetworkingIdentity {
+        use sys::ESteamNetworkingIdentityType::*;
+        unsafe {
+            let data = raw.__bindgen_anon_1;
+            let len = (raw.m_cbSize.max(0) as usize).min(MAX_GENERIC_BYTES);
+            match raw.m_eType {
+                k_ESteamNetworkingIdentityType_SteamID => NetworkingIdentity::SteamId(SteamId(data.m_steamID64)),
+                k_ESteamNetworkingIdentityType_IPAddress => NetworkingIdentity::IpAddress(NetworkingIpAddr::from_sys(&data.m_ip)),
+                k_ESteamNetworkingIdentityType_GenericString => {
+                    let bytes = data.m_genericBytes;
+                    let end = bytes.iter().position(|&v| v == 0).unwrap_or(bytes.len());
+                    NetworkingIdentity::GenericString(String::from_utf8_lossy(&bytes[..end]).into_owned())
+                },
+                k_ESteamNetworkingIdentityType_GenericBytes => NetworkingIdentity::GenericBytes(data.m_genericBytes[..len].to_vec()),
+                _ => NetworkingIdentity::Invalid,
+            }
+        }
+    }
+}
+
+impl From<SteamId> for NetworkingIdentity {
+    fn from(id: SteamId) -> NetworkingIdentity {
+        NetworkingIdentity::SteamId(id)
+    }
+}
+
+impl From<NetworkingIpAddr> for NetworkingIdentity {
+    fn from(addr: NetworkingIpAddr) -> NetworkingIdentity {
+        NetworkingIdentity::IpAddress(addr)
+    }
+}
+
+impl fmt::Display for NetworkingIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NetworkingIdentity::Invalid => write!(f, "invalid"),
+            NetworkingIdentity::SteamId(id) => write!(f, "steamid:{}", id.raw()),
+            NetworkingIdentity::IpAddress(addr) => write!(f, "ip:{}", addr),
+            NetworkingIdentity::GenericString(s) => write!(f, "str:{}", s),
+            NetworkingIdentity::GenericBytes(b) => {
+                write!(f, "gen:")?;
+                for v in b {
+                    write!(f, "{:02x}", v)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+impl FromStr for NetworkingIdentity {
+    type Err = InvalidNetworkingAddress;
+
+    fn from_str(s: &str) -> Result<NetworkingIdentity, InvalidNetworkingAddress> {
+        let s = s.trim();
+        if s == "invalid" {
+            return Ok(NetworkingIdentity::Invalid);
+        }
+        let mut parts = s.splitn(2, ':');
+        let kind = parts.next().ok_or(InvalidNetworkingAddress)?;
+        let value = parts.next().ok_or(InvalidNetworkingAddress)?;
+        let identity = match kind {
+            "steamid" => NetworkingIdentity::SteamId(SteamId(value.parse().map_err(|_| InvalidNetworkingAddress)?)),
+            "ip" => NetworkingIdentity::IpAddress(value.parse()?),
+            "str" => NetworkingIdentity::GenericString(value.to_owned()),
+            "gen" => {
+                if value.len() % 2 != 0 || !value.is_ascii() {
+                    return Err(InvalidNetworkingAddress);
+                }
+                let bytes = (0 .. value.len()).step_by(2)
+                    .map(|i| u8::from_str_radix(&value[i .. i + 2], 16))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| InvalidNetworkingAddress)?;
+                NetworkingIdentity::GenericBytes(bytes)
+            },
+            _ => return Err(InvalidNetworkingAddress),
+        };
+        if identity.is_valid() {
+            Ok(identity)
+        } else {
+            Err(InvalidNetworkingAddress)
+        }
+    }
+}
+
+/// Config options applied to a connection or listen socket when
+/// it is created.
+///
+/// Setting options at creation time means they are in effect before
+/// the first packet is sent. Options set on a listen socket are
+/// inherited by the connections it accepts.
+///
+/// ```no_run
+/// # use steamworks::*;
+/// # let (client, single) = Client::init().unwrap();
+/// let config = NetworkingConfig::new()
+///     .timeout_initial(5000)
+///     .send_buffer_size(1024 * 1024);
+/// let socket = client.networking_sockets()
+///     .create_listen_socket_p2p(0, &config);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetworkingConfig {
+    values: Vec<(sys::ESteamNetworkingConfigValue, i32)>,
+}
+
+impl NetworkingConfig {
+    /// Creates an empty config which uses the defaults for
+    /// every option
+    pub fn new() -> NetworkingConfig {
+        NetworkingConfig::default()
+    }
+
+    fn set(mut self, option: sys::ESteamNetworkingConfigValue, value: i32) -> Self {
+        self.values.retain(|&(v, _)| v != option);
+        self.values.push((option, value));
+        self
+    }
+
+    /// Sets how long to wait for the connection to be established
+    /// in milliseconds
+    pub fn timeout_initial(self, ms: i32) -> Self {
+        self.set(sys::ESteamNetworkingConfigValue::k_ESteamNetworkingConfig_TimeoutInitial, ms)
+    }
+
+    /// Sets how long an established connection may go without
+    /// hearing from the peer in milliseconds
+    pub fn timeout_connected(self, ms: i32) -> Self {
+        self.set(sys::ESteamNetworkingConfigValue::k_ESteamNetworkingConfig_TimeoutConnected, ms)
+    }
+
+    /// Sets how many bytes may be queued for sending before
+    /// sending fails with `SteamError::LimitExceeded`
+    pub fn send_buffer_size(self, bytes: i32) -> Self {
+        self.set(sys::ESteamNetworkingConfigValue::k_ESteamNetworkingConfig_SendBufferSize, bytes)
+    }
+
+    /// Sets the minimum and maximum send rate in bytes per second
+    pub fn send_rate(self, min: i32, max: i32) -> Self {
+        self.set(sys::ESteamNetworkingConfigValue::k_ESteamNetworkingConfig_SendRateMin, min)
+            .set(sys::ESteamNetworkingConfigValue::k_ESteamNetworkingConfig_SendRateMax, max)
+    }
+
+    /// Sets how long messages are held back to be combined with
+    /// others in microseconds
+    pub fn nagle_time(self, us: i32) -> Self {
+        self.set(sys::ESteamNetworkingConfigValue::k_ESteamNetworkingConfig_NagleTime, us)
+    }
+
+    /// Sets the largest packet that will be sent in bytes
+    pub fn mtu_packet_size(self, bytes: i32) -> Self {
+        self.set(sys::ESteamNetworkingConfigValue::k_ESteamNetworkingConfig_MTU_PacketSize, bytes)
+    }
+
+    /// Sets whether ip connections are allowed from peers without
+    /// a certificate.
+    ///
+    /// This is insecure and should only be used for development.
+    pub fn ip_allow_without_auth(self, allow: bool) -> Self {
+        self.set(sys::ESteamNetworkingConfigValue::k_ESteamNetworkingConfig_IP_AllowWithoutAuth, allow as i32)
+    }
+
+    pub(crate) fn to_sys(&self) -> Vec<sys::SteamNetworkingConfigValue_t> {
+        self.values.iter()
+            .map(|&(option, value)| sys::SteamNetworkingConfigValue_t {
+                m_eValue: option,
+                m_eDataType: sys::ESteamNetworkingConfigDataType::k_ESteamNetworkingConfig_Int32,
+                m_val: sys::SteamNetworkingConfigValue_t__bindgen_ty_1 { m_int32: value },
+            })
+            .collect()
+    }
+}
+
+/// The availability of a networking service, such as the relay
+/// network or authentication certificates
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NetworkingAvailability {
+    /// The service can't be used, e.g. because steam isn't running
+    CannotTry,
+    /// The service failed and isn't being retried
+    Failed,
+    /// The service was available but has since been lost
+    Previously,
+    /// The service failed and is being retried
+    Retrying,
+    /// Nothing has tried to use the service yet
+    NeverTried,
+    /// The service is waiting on something else before trying
+    Waiting,
+    /// The service is being set up
+    Attempting,
+    /// The service is ready to use
+    Current,
+    /// The state of the service is unknown
+    Unknown,
+}
+
+impl NetworkingAvailability {
+    /// Returns whether the service failed or can't be used
+    pub fn is_failure(&self) -> bool {
+        matches!(self,
+            NetworkingAvailability::CannotTry
+            | NetworkingAvailability::Failed
+            | NetworkingAvailability::Previously)
+    }
+}
+
+impl From<sys::ESteamNetworkingAvailability> for NetworkingAvailability {
+    fn from(availability: sys::ESteamNetworkingAvailability) -> Self {
+        use sys::ESteamNetworkingAvailability::*;
+        match availability {
+            k_ESteamNetworkingAvailability_CannotTry => NetworkingAvailability::CannotTry,
+            k_ESteamNetworkingAvailability_Failed => NetworkingAvailability::Failed,
+            k_ESteamNetworkingAvailability_Previously => NetworkingAvailability::Previously,
+            k_ESteamNetworkingAvailability_Retrying => NetworkingAvailability::Retrying,
+            k_ESteamNetworkingAvailability_NeverTried => NetworkingAvailability::NeverTried,
+            k_ESteamNetworkingAvailability_Waiting => NetworkingAvailability::Waiting,
+            k_ESteamNetworkingAvailability_Attempting => NetworkingAvailability::Attempting,
+            k_ESteamNetworkingAvailability_Current => NetworkingAvailability::Current,
+            _ => NetworkingAvailability::Unknown,
+        }
+    }
+}
+
+/// Returned when a string couldn't be parsed as a networking
+/// address or identity
+#[derive(Clone, Copy, Debug, Fail, PartialEq, Eq)]
+#[fail(display = "invalid networking address")]
+pub struct InvalidNetworkingAddress;
+
+#[test]
+fn networking_ip_addr_formats() {
+    let addr: NetworkingIpAddr = "127.0.0.1:27015".parse().unwrap();
+    assert!(addr.is_ipv4());
+    assert_eq!(addr.port(), 27015);
+    assert_eq!(addr.to_string(), "127.0.0.1:27015");
+    assert_eq!(NetworkingIpAddr::from_sys(&addr.to_sys()), addr);
+
+    let addr: NetworkingIpAddr = "[::1]:80".parse().unwrap();
+    assert!(addr.is_localhost());
+    assert_eq!(addr.to_string(), "[::1]:80");
+    assert_eq!(NetworkingIpAddr::from_sys(&addr.to_sys()), addr);
+
+    let addr: NetworkingIpAddr = "10.0.0.1".parse().unwrap();
+    assert_eq!(addr.port(), 0);
+    assert!("not an ip".parse::<NetworkingIpAddr>().is_err());
+}
+
+#[test]
+fn networking_identity_formats() {
+    for s in &["steamid:76561198174976054", "ip:127.0.0.1:27015", "str:hello", "gen:00ff10", "invalid"] {
+        let identity: NetworkingIdentity = s.parse().unwrap();
+        assert_eq!(identity.to_string(), *s);
+        assert_eq!(NetworkingIdentity::from_sys(&identity.to_sys()), identity);
+    }
+    assert!("gen:0".parse::<NetworkingIdentity>().is_err());
+    assert!("str:".parse::<NetworkingIdentity>().is_err());
+    assert!("foo:bar".parse::<NetworkingIdentity>().is_err());
+}
+
+#[test]
+fn networking_config_values() {
+    let config = NetworkingConfig::new()
+        .timeout_initial(1000)
+        .send_rate(10, 20)
+        .timeout_initial(2000);
+    let values = config.to_sys();
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[2].m_eValue, sys::ESteamNetworkingConfigValue::k_ESteamNetworkingConfig_TimeoutInitial);
+    assert_eq!(unsafe { values[2].m_val.m_int32 }, 2000);
+    assert!(NetworkingConfig::new().to_sys().is_empty());
+}
@@ -0,0 +1,208 @@
+use super::*;
+use libc::c_char;
+use std::panic;
+use std::process::abort;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// Access to the steam networking utils interface
+pub struct NetworkingUtils<Manager> {
+    pub(crate) utils: *mut sys::ISteamNetworkingUtils,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+/// How much debug output the networking sockets library produces
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NetworkingDebugLevel {
+    /// No output
+    None,
+    /// The api was used incorrectly or an internal error happened
+    Bug,
+    /// Rare run-time errors that should go away, e.g. a dropped connection
+    Error,
+    /// Nothing is wrong but this is an important notification
+    Important,
+    /// Warnings
+    Warning,
+    /// The recommended amount of output
+    Msg,
+    /// Quite a bit of output
+    Verbose,
+    /// Practically everything
+    Debug,
+    /// Everything, including detailed packet contents
+    Everything,
+}
+
+impl Into<sys::ESteamNetworkingSocketsDebugOutputType> for NetworkingDebugLevel {
+    fn into(self) -> sys::ESteamNetworkingSocketsDebugOutputType {
+        use sys::ESteamNetworkingSocketsDebugOutputType::*;
+        match self {
+            NetworkingDebugLevel::None => k_ESteamNetworkingSocketsDebugOutputType_None,
+            NetworkingDebugLevel::Bug => k_ESteamNetworkingSocketsDebugOutputType_Bug,
+            NetworkingDebugLevel::Error => k_ESteamNetworkingSocketsDebugOutputType_Error,
+            NetworkingDebugLevel::Important => k_ESteamNetworkingSocketsDebugOutputType_Important,
+            NetworkingDebugLevel::Warning => k_ESteamNetworkingSocketsDebugOutputType_Warning,
+            NetworkingDebugLevel::Msg => k_ESteamNetworkingSocketsDebugOutputType_Msg,
+            NetworkingDebugLevel::Verbose => k_ESteamNetworkingSocketsDebugOutputType_Verbose,
+            NetworkingDebugLevel::Debug => k_ESteamNetworkingSocketsDebugOutputType_Debug,
+            NetworkingDebugLevel::Everything => k_ESteamNetworkingSocketsDebugOutputType_Everything,
+        }
+    }
+}
+
+impl From<sys::ESteamNetworkingSocketsDebugOutputType> for NetworkingDebugLevel {
+    fn from(level: sys::ESteamNetworkingSocketsDebugOutputType) -> Self {
+        use sys::ESteamNetworkingSocketsDebugOutputType::*;
+        match level {
+            k_ESteamNetworkingSocketsDebugOutputType_Bug => NetworkingDebugLevel::Bug,
+            k_ESteamNetworkingSocketsDebugOutputType_Error => NetworkingDebugLevel::Error,
+            k_ESteamNetworkingSocketsDebugOutputType_Important => NetworkingDebugLevel::Important,
+            k_ESteamNetworkingSocketsDebugOutputType_Warning => NetworkingDebugLevel::Warning,
+            k_ESteamNetworkingSocketsDebugOutputType_Msg => NetworkingDebugLevel::Msg,
+            k_ESteamNetworkingSocketsDebugOutputType_Verbose => NetworkingDebugLevel::Verbose,
+            k_ESteamNetworkingSocketsDebugOutputType_Debug => NetworkingDebugLevel::Debug,
+            k_ESteamNetworkingSocketsDebugOutputType_Everything => NetworkingDebugLevel::Everything,
+            _ => NetworkingDebugLevel::None,
+        }
+    }
+}
+
+lazy_static! {
+    /// Global rust debug output callback
+    static ref DEBUG_OUTPUT_CALLBACK: RwLock<Option<Box<dyn Fn(NetworkingDebugLevel, &CStr) + Send + Sync>>> = RwLock::new(None);
+}
+
+/// C function to pass as the real callback, which forwards to the `DEBUG_OUTPUT_CALLBACK` if any
+unsafe extern "C" fn c_debug_output_callback(level: sys::ESteamNetworkingSocketsDebugOutputType, msg: *const c_char) {
+    let lock = DEBUG_OUTPUT_CALLBACK.read().expect("debug output func lock poisoned");
+    let cb = match lock.as_ref() {
+        Some(cb) => cb,
+        None => { return; }
+    };
+
+    let s = CStr::from_ptr(msg);
+
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(||
+        cb(level.into(), s)
+    ));
+    if res.is_err() {
+        println!("Steam networking debug output callback panicked");
+        abort();
+    }
+}
+
+/// Converts a networking timestamp into an `Instant` given the
+/// current timestamp and instant
+fn instant_from_timestamp(timestamp: i64, now_timestamp: i64, now: Instant) -> Instant {
+    if timestamp <= now_timestamp {
+        let ago = Duration::from_micros((now_timestamp - timestamp) as u64);
+        now.checked_sub(ago).unwrap_or(now)
+    } else {
+        now + Duration::from_micros((timestamp - now_timestamp) as u64)
+    }
+}
+
+impl <Manager> NetworkingUtils<Manager> {
+    /// Returns the raw `ISteamNetworkingUtils` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamNetworkingUtils {
+        self.utils
+    }
+
+    /// Starts connecting to the relay network in the background.
+    ///
+    /// Connecting through the relay takes a few seconds the first
+    /// time, calling this early avoids the delay when the first p2p
+    /// connection is made.
+    pub fn init_relay_network_access(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamNetworkingUtils_InitRelayNetworkAccess(self.utils);
+        }
+    }
+
+    /// Returns whether the relay network is ready to use
+    pub fn relay_network_status(&self) -> NetworkingAvailability {
+        unsafe {
+            sys::SteamAPI_ISteamNetworkingUtils_GetRelayNetworkStatus(self.utils, std::ptr::null_mut()).into()
+        }
+    }
+
+    /// Returns the current time of the networking clock in
+    /// microseconds.
+    ///
+    /// This is the clock used for timestamps such as
+    /// `NetworkingMessage::time_received`. Its value has no meaning
+    /// on its own, only differences between timestamps do.
+    pub fn local_timestamp(&self) -> i64 {
+        unsafe {
+            sys::SteamAPI_ISteamNetworkingUtils_GetLocalTimestamp(self.utils)
+        }
+    }
+
+    /// Converts a timestamp from the networking clock into an
+    /// `Instant`, so it can be compared with the game's own timings
+    pub fn timestamp_to_instant(&self, timestamp: i64) -> Instant {
+        let now = Instant::now();
+        instant_from_timestamp(timestamp, self.local_timestamp(), now)
+    }
+
+    /// Sets the callback that receives debug output from the
+    /// networking sockets library.
+    ///
+    /// No output is produced until a level is set with
+    /// `set_debug_output_level`.
+    pub fn set_debug_output_callback<F>(&self, cb: F)
+        where F: Fn(NetworkingDebugLevel, &CStr) + Send + Sync + 'static
+    {
+        let mut lock = DEBUG_OUTPUT_CALLBACK.write().expect("debug output func lock poisoned");
+        *lock = Some(Box::new(cb));
+    }
+
+    /// Sets how much debug output is passed to the callback set
+    /// with `set_debug_output_callback`.
+    ///
+    /// `NetworkingDebugLevel::None` turns the output off. The higher
+    /// levels are only meant for debugging as they are expensive.
+    pub fn set_debug_output_level(&self, level: NetworkingDebugLevel) {
+        unsafe {
+            let cb = if level == NetworkingDebugLevel::None {
+                None
+            } else {
+                Some(c_debug_output_callback as unsafe extern "C" fn(_, _))
+            };
+            sys::SteamAPI_ISteamNetworkingUtils_SetDebugOutputFunction(self.utils, level.into(), cb);
+        }
+    }
+
+    /// Allocates a message with a buffer of the given size to be
+    /// sent with `NetworkingSockets::send_messages`.
+    ///
+    /// Returns `None` if the allocation failed.
+    pub fn allocate_message(&self, size: usize) -> Option<NetworkingMessage> {
+        unsafe {
+            let message = sys::SteamAPI_ISteamNetworkingUtils_AllocateMessage(self.utils, size as _);
+            if message.is_null() {
+                None
+            } else {
+                Some(NetworkingMessage { message })
+            }
+        }
+    }
+}
+
+#[test]
+fn timestamp_conversion() {
+    let now = Instant::now() + Duration::from_secs(10);
+    assert_eq!(instant_from_timestamp(5_000_000, 5_000_000, now), now);
+    assert_eq!(instant_from_timestamp(4_000_000, 5_000_000, now), now - Duration::from_secs(1));
+    assert_eq!(instant_from_timestamp(5_500_000, 5_000_000, now), now + Duration::from_millis(500));
+}
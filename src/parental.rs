@@ -0,0 +1,123 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 5000;
+
+/// Access to the steam parental settings interface
+pub struct ParentalSettings<Manager> {
+    pub(crate) parental: *mut sys::ISteamParentalSettings,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+/// A steam feature that can be restricted by family view
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ParentalFeature {
+    Store,
+    Community,
+    Profile,
+    Friends,
+    News,
+    Trading,
+    Settings,
+    Console,
+    Browser,
+    ParentalSetup,
+    Library,
+    Test,
+    SiteLicense,
+}
+
+impl Into<sys::EParentalFeature> for ParentalFeature {
+    fn into(self) -> sys::EParentalFeature {
+        match self {
+            ParentalFeature::Store => sys::EParentalFeature::k_EFeatureStore,
+            ParentalFeature::Community => sys::EParentalFeature::k_EFeatureCommunity,
+            ParentalFeature::Profile => sys::EParentalFeature::k_EFeatureProfile,
+            ParentalFeature::Friends => sys::EParentalFeature::k_EFeatureFriends,
+            ParentalFeature::News => sys::EParentalFeature::k_EFeatureNews,
+            ParentalFeature::Trading => sys::EParentalFeature::k_EFeatureTrading,
+            ParentalFeature::Settings => sys::EParentalFeature::k_EFeatureSettings,
+            ParentalFeature::Console => sys::EParentalFeature::k_EFeatureConsole,
+            ParentalFeature::Browser => sys::EParentalFeature::k_EFeatureBrowser,
+            ParentalFeature::ParentalSetup => sys::EParentalFeature::k_EFeatureParentalSetup,
+            ParentalFeature::Library => sys::EParentalFeature::k_EFeatureLibrary,
+            ParentalFeature::Test => sys::EParentalFeature::k_EFeatureTest,
+            ParentalFeature::SiteLicense => sys::EParentalFeature::k_EFeatureSiteLicense,
+        }
+    }
+}
+
+impl <Manager> ParentalSettings<Manager> {
+    /// Returns the raw `ISteamParentalSettings` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamParentalSettings {
+        self.parental
+    }
+
+    /// Returns whether family view is enabled for the current user
+    pub fn is_parental_lock_enabled(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamParentalSettings_BIsParentalLockEnabled(self.parental)
+        }
+    }
+
+    /// Returns whether family view is currently locked.
+    ///
+    /// While unlocked with the family view PIN no restrictions apply.
+    pub fn is_parental_lock_locked(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamParentalSettings_BIsParentalLockLocked(self.parental)
+        }
+    }
+
+    /// Returns whether the app is currently blocked by family view
+    pub fn is_app_blocked(&self, app_id: AppId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamParentalSettings_BIsAppBlocked(self.parental, app_id.0)
+        }
+    }
+
+    /// Returns whether the app is blocked by family view while it
+    /// is locked
+    pub fn is_app_in_block_list(&self, app_id: AppId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamParentalSettings_BIsAppInBlockList(self.parental, app_id.0)
+        }
+    }
+
+    /// Returns whether the feature is currently blocked by family view
+    pub fn is_feature_blocked(&self, feature: ParentalFeature) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamParentalSettings_BIsFeatureBlocked(self.parental, feature.into())
+        }
+    }
+
+    /// Returns whether the feature is blocked by family view while it
+    /// is locked
+    pub fn is_feature_in_block_list(&self, feature: ParentalFeature) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamParentalSettings_BIsFeatureInBlockList(self.parental, feature.into())
+        }
+    }
+}
+
+/// Called when the family view settings change or family view is
+/// locked or unlocked
+#[derive(Clone, Debug)]
+pub struct ParentalSettingsChanged;
+
+unsafe impl Callback for ParentalSettingsChanged {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamParentalSettingsChanged_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        ParentalSettingsChanged
+    }
+}
@@ -0,0 +1,360 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 5300;
+
+/// Access to the steam parties interface
+///
+/// Parties allow a game to advertise open slots in a group through
+/// beacons placed in locations such as steam chat rooms.
+pub struct Parties<Manager> {
+    pub(crate) parties: *mut sys::ISteamParties,
+    pub(crate) inner: Arc<Inner<Manager>>,
+}
+
+/// The id of a party beacon
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartyBeaconId(pub(crate) sys::PartyBeaconID_t);
+
+impl PartyBeaconId {
+    /// Creates a `PartyBeaconId` from a raw 64 bit value.
+    pub fn from_raw(id: u64) -> PartyBeaconId {
+        PartyBeaconId(id)
+    }
+
+    /// Returns the raw 64 bit value of the beacon id
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The type of a location a beacon can be placed in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BeaconLocationType {
+    Invalid,
+    ChatGroup,
+}
+
+/// A location a beacon can be placed in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BeaconLocation {
+    pub location_type: BeaconLocationType,
+    pub location_id: u64,
+}
+
+impl BeaconLocation {
+    fn from_sys(location: sys::SteamPartyBeaconLocation_t) -> BeaconLocation {
+        BeaconLocation {
+            location_type: match location.m_eType {
+                sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_ChatGroup => BeaconLocationType::ChatGroup,
+                _ => BeaconLocationType::Invalid,
+            },
+            location_id: location.m_ulLocationID,
+        }
+    }
+
+    fn to_sys(self) -> sys::SteamPartyBeaconLocation_t {
+        sys::SteamPartyBeaconLocation_t {
+            m_eType: match self.location_type {
+                BeaconLocationType::Invalid => sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_Invalid,
+                BeaconLocationType::ChatGroup => sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_ChatGroup,
+            },
+            m_ulLocationID: self.location_id,
+        }
+    }
+}
+
+/// Information about a beacon location that can be shown to the user
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BeaconLocationData {
+    Name,
+    IconUrlSmall,
+    IconUrlMedium,
+    IconUrlLarge,
+}
+
+impl Into<sys::ESteamPartyBeaconLocationData> for BeaconLocationData {
+    fn into(self) -> sys::ESteamPartyBeaconLocationData {
+        match self {
+            BeaconLocationData::Name => sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataName,
+            BeaconLocationData::IconUrlSmall => sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataIconURLSmall,
+            BeaconLocationData::IconUrlMedium => sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataIconURLMedium,
+            BeaconLocationData::IconUrlLarge => sys::ESteamPartyBeaconLocationData::k_ESteamPartyBeaconLocationDataIconURLLarge,
+        }
+    }
+}
+
+/// The details of an active beacon
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BeaconDetails {
+    /// The user that created the beacon
+    pub owner: SteamId,
+    /// The location the beacon is placed in
+    pub location: BeaconLocation,
+    /// The metadata passed to `Parties::create_beacon`
+    pub metadata: String,
+}
+
+/// The result of joining a party with `Parties::join_party`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JoinedParty {
+    /// The beacon that was joined
+    pub beacon: PartyBeaconId,
+    /// The user that created the beacon
+    pub owner: SteamId,
+    /// The connect string passed to `Parties::create_beacon`, used
+    /// to connect to the owner's game
+    pub connect_string: String,
+}
+
+impl <Manager> Parties<Manager> {
+    /// Returns the raw `ISteamParties` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamParties {
+        self.parties
+    }
+
+    /// Returns the locations that beacons can currently be placed in
+    pub fn available_beacon_locations(&self) -> Vec<BeaconLocation> {
+        unsafe {
+            let mut count = 0;
+            if !sys::SteamAPI_ISteamParties_GetNumAvailableBeaconLocations(self.parties, &mut count) || count == 0 {
+                return Vec::new();
+            }
+            let mut locations = vec![BeaconLocation {
+                location_type: BeaconLocationType::Invalid,
+                location_id: 0,
+            }.to_sys(); count as usize];
+            if !sys::SteamAPI_ISteamParties_GetAvailableBeaconLocations(self.parties, locations.as_mut_ptr(), count) {
+                return Vec::new();
+            }
+            locations.into_iter()
+                .map(BeaconLocation::from_sys)
+                .collect()
+        }
+    }
+
+    /// Returns information about a beacon location such as its name
+    pub fn beacon_location_data(&self, location: BeaconLocation, data: BeaconLocationData) -> Option<String> {
+        unsafe {
+            let mut buffer = vec![0; 1024];
+            if sys::SteamAPI_ISteamParties_GetBeaconLocationData(
+                self.parties, location.to_sys(), data.into(),
+                buffer.as_mut_ptr(), buffer.len() as _
+            ) {
+                Some(CStr::from_ptr(buffer.as_ptr()).to_string_lossy().into_owned())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Creates a beacon advertising the given number of open slots.
+    ///
+    /// The connect string is given to users that join through the
+    /// beacon and should contain what they need to connect to the
+    /// game. The metadata is shown alongside the beacon.
+    ///
+    /// The beacon must be destroyed with `destroy_beacon` once it is
+    /// no longer needed.
+    pub fn create_beacon<F>(&self, open_slots: u32, location: BeaconLocation, connect_string: &str, metadata: &str, mut cb: F)
+        where F: FnMut(SResult<PartyBeaconId>) + 'static + Send
+    {
+        let connect_string = CString::new(connect_string).unwrap();
+        let metadata = CString::new(metadata).unwrap();
+        let mut location = location.to_sys();
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamParties_CreateBeacon(
+                self.parties, open_slots, &mut location,
+                connect_string.as_ptr(), metadata.as_ptr()
+            );
+            register_call_result::<sys::CreateBeaconCallback_t, _, _>(
+                &self.inner, api_call, CALLBACK_BASE_ID + 2,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(PartyBeaconId(v.m_ulBeaconID))
+                    })
+                }
+            );
+        }
+    }
+
+    /// Notifies steam that a user who joined through the beacon has
+    /// connected to the game, filling their reserved slot
+    pub fn on_reservation_completed(&self, beacon: PartyBeaconId, user: SteamId) {
+        unsafe {
+            sys::SteamAPI_ISteamParties_OnReservationCompleted(self.parties, beacon.0, user.0);
+        }
+    }
+
+    /// Notifies steam that a user who joined through the beacon will
+    /// not be connecting, freeing their reserved slot
+    pub fn cancel_reservation(&self, beacon: PartyBeaconId, user: SteamId) {
+        unsafe {
+            sys::SteamAPI_ISteamParties_CancelReservation(self.parties, beacon.0, user.0);
+        }
+    }
+
+    /// Changes the number of open slots advertised by the beacon
+    pub fn change_num_open_slots<F>(&self, beacon: PartyBeaconId, open_slots: u32, mut cb: F)
+        where F: FnMut(SResult<()>) + 'static + Send
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamParties_ChangeNumOpenSlots(self.parties, beacon.0, open_slots);
+            register_call_result::<sys::ChangeNumOpenSlotsCallback_t, _, _>(
+                &self.inner, api_call, CALLBACK_BASE_ID + 4,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        Ok(())
+                    })
+                }
+            );
+        }
+    }
+
+    /// Destroys a beacon created with `create_beacon`
+    pub fn destroy_beacon(&self, beacon: PartyBeaconId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamParties_DestroyBeacon(self.parties, beacon.0)
+        }
+    }
+
+    /// Returns the beacons that are visible to the user
+    pub fn active_beacons(&self) -> Vec<PartyBeaconId> {
+        unsafe {
+            let count = sys::SteamAPI_ISteamParties_GetNumActiveBeacons(self.parties);
+            (0 .. count)
+                .map(|idx| sys::SteamAPI_ISteamParties_GetBeaconByIndex(self.parties, idx))
+                .filter(|&id| id != sys::k_ulPartyBeaconIdInvalid)
+                .map(PartyBeaconId)
+                .collect()
+        }
+    }
+
+    /// Returns the details of a beacon visible to the user
+    pub fn beacon_details(&self, beacon: PartyBeaconId) -> Option<BeaconDetails> {
+        unsafe {
+            let mut owner = std::mem::zeroed::<sys::CSteamID>();
+            let mut location = BeaconLocation {
+                location_type: BeaconLocationType::Invalid,
+                location_id: 0,
+            }.to_sys();
+            let mut metadata = vec![0; sys::k_cchDeveloperMetadataMax as usize + 1];
+            if sys::SteamAPI_ISteamParties_GetBeaconDetails(
+                self.parties, beacon.0,
+                &mut owner, &mut location,
+                metadata.as_mut_ptr(), metadata.len() as _
+            ) {
+                Some(BeaconDetails {
+                    owner: SteamId(owner.m_steamid.m_unAll64Bits),
+                    location: BeaconLocation::from_sys(location),
+                    metadata: CStr::from_ptr(metadata.as_ptr()).to_string_lossy().into_owned(),
+                })
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Joins the party advertised by the beacon, reserving a slot.
+    ///
+    /// On success the callback receives the connect string that should
+    /// be used to connect to the owner's game.
+    pub fn join_party<F>(&self, beacon: PartyBeaconId, mut cb: F)
+        where F: FnMut(SResult<JoinedParty>) + 'static + Send
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamParties_JoinParty(self.parties, beacon.0);
+            register_call_result::<sys::JoinPartyCallback_t, _, _>(
+                &self.inner, api_call, CALLBACK_BASE_ID + 1,
+                move |v, io_error| {
+                    cb(if io_error {
+                        Err(SteamError::IOFailure)
+                    } else if v.m_eResult != sys::EResult::k_EResultOK {
+                        Err(v.m_eResult.into())
+                    } else {
+                        let connect_string = v.m_rgchConnectString;
+                        Ok(JoinedParty {
+                            beacon: PartyBeaconId(v.m_ulBeaconID),
+                            owner: SteamId(v.m_SteamIDBeaconOwner.m_steamid.m_unAll64Bits),
+                            connect_string: CStr::from_ptr(connect_string.as_ptr()).to_string_lossy().into_owned(),
+                        })
+                    })
+                }
+            );
+        }
+    }
+}
+
+/// Called on the beacon owner's side when a user joins through
+/// the beacon.
+///
+/// Once the user has connected to the game the reservation should
+/// be completed with `Parties::on_reservation_completed`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ReservationNotification {
+    /// The beacon that was joined
+    pub beacon: PartyBeaconId,
+    /// The user that joined
+    pub joiner: SteamId,
+}
+
+unsafe impl Callback for ReservationNotification {
+    const ID: i32 = CALLBACK_BASE_ID + 3;
+    const SIZE: i32 = ::std::mem::size_of::<sys::ReservationNotificationCallback_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::ReservationNotificationCallback_t);
+        ReservationNotification {
+            beacon: PartyBeaconId(val.m_ulBeaconID),
+            joiner: SteamId(val.m_steamIDJoiner.m_steamid.m_unAll64Bits),
+        }
+    }
+}
+
+/// Called when the list of available beacon locations changes
+#[derive(Clone, Debug)]
+pub struct AvailableBeaconLocationsUpdated;
+
+unsafe impl Callback for AvailableBeaconLocationsUpdated {
+    const ID: i32 = CALLBACK_BASE_ID + 5;
+    const SIZE: i32 = ::std::mem::size_of::<sys::AvailableBeaconLocationsUpdated_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        AvailableBeaconLocationsUpdated
+    }
+}
+
+/// Called when the list of beacons visible to the user changes
+#[derive(Clone, Debug)]
+pub struct ActiveBeaconsUpdated;
+
+unsafe impl Callback for ActiveBeaconsUpdated {
+    const ID: i32 = CALLBACK_BASE_ID + 6;
+    const SIZE: i32 = ::std::mem::size_of::<sys::ActiveBeaconsUpdated_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        ActiveBeaconsUpdated
+    }
+}
@@ -0,0 +1,260 @@
+use super::*;
+use std::time::{Duration, Instant};
+
+/// The state of a `PeerConnection`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PeerState {
+    /// Waiting for the relay network and authentication before
+    /// the first attempt
+    WarmingUp,
+    /// The first attempt to connect is running
+    Connecting,
+    /// The connection is established
+    Connected,
+    /// The connection failed or was lost and is being tried again
+    Reconnecting,
+    /// The connection failed and won't be tried again
+    Failed,
+}
+
+/// Events produced by `PeerConnection::poll`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PeerEvent {
+    /// The connection was established
+    Connected,
+    /// The connection failed or was lost and is being tried again
+    Reconnecting {
+        reason: NetConnectionEnd,
+        attempt: u32,
+    },
+    /// The connection failed and won't be tried again
+    Failed(NetConnectionEnd),
+}
+
+/// A p2p connection to a steam user that retries and reconnects
+/// by itself.
+///
+/// Created with `NetworkingSockets::connect_to_peer`. The connection
+/// waits for the relay network before the first attempt, gives up on
+/// attempts that don't connect within the timeout and reconnects if
+/// an established connection is lost. Progress is reported by `poll`,
+/// which should be called regularly after running callbacks.
+///
+/// The state is read from the connection directly so this doesn't
+/// register a `NetConnectionStatusChanged` callback.
+///
+/// The connection is closed when this is dropped.
+pub struct PeerConnection<Manager> {
+    sockets: NetworkingSockets<Manager>,
+    utils: NetworkingUtils<Manager>,
+    peer: SteamId,
+    virtual_port: i32,
+    connect_timeout: Duration,
+    max_retries: u32,
+    config: NetworkingConfig,
+
+    connection: Option<NetConnection>,
+    state: PeerState,
+    attempt: u32,
+    started: Instant,
+}
+
+impl <Manager> PeerConnection<Manager> {
+    pub(crate) fn new(sockets: NetworkingSockets<Manager>, utils: NetworkingUtils<Manager>, peer: SteamId) -> PeerConnection<Manager> {
+        utils.init_relay_network_access();
+        sockets.init_authentication();
+        PeerConnection {
+            sockets,
+            utils,
+            peer,
+            virtual_port: 0,
+            connect_timeout: Duration::from_secs(10),
+            max_retries: 3,
+            config: NetworkingConfig::new(),
+
+            connection: None,
+            state: PeerState::WarmingUp,
+            attempt: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Sets the virtual port to connect to. Defaults to 0
+    pub fn virtual_port(mut self, virtual_port: i32) -> Self {
+        self.virtual_port = virtual_port;
+        self
+    }
+
+    /// Sets how long an attempt may take before it is given up on.
+    /// Defaults to 10 seconds.
+    ///
+    /// This is also the longest time spent waiting for the relay
+    /// network before the first attempt.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets how many times in a row a failed attempt is retried
+    /// before giving up. Defaults to 3.
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Sets the config applied to every attempt
+    pub fn config(mut self, config: NetworkingConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Returns the user being connected to
+    pub fn peer(&self) -> SteamId {
+        self.peer
+    }
+
+    /// Returns the current state of the connection
+    pub fn state(&self) -> PeerState {
+        self.state
+    }
+
+    /// Returns the handle of the current attempt, if one is running.
+    ///
+    /// The handle changes on every reconnect.
+    pub fn connection(&self) -> Option<NetConnection> {
+        self.connection
+    }
+
+    /// Sends a message to the peer.
+    ///
+    /// Fails with `SteamError::NoConnection` if the connection isn't
+    /// established.
+    pub fn send_message(&self, data: &[u8], flags: SendFlags) -> SResult<i64> {
+        match self.connection {
+            Some(connection) if self.state == PeerState::Connected => {
+                self.sockets.send_message(connection, data, flags)
+            },
+            _ => Err(SteamError::NoConnection),
+        }
+    }
+
+    /// Receives up to `max_messages` pending messages from the peer
+    pub fn receive_messages(&self, max_messages: usize) -> Vec<NetworkingMessage> {
+        match self.connection {
+            Some(connection) if self.state == PeerState::Connected => {
+                self.sockets.receive_messages_on_connection(connection, max_messages)
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    /// Advances the connection, returning an event if its state
+    /// changed.
+    pub fn poll(&mut self) -> Option<PeerEvent> {
+        match self.state {
+            PeerState::Failed => None,
+            PeerState::WarmingUp => {
+                let relay = self.utils.relay_network_status();
+                if relay == NetworkingAvailability::Current
+                    || relay.is_failure()
+                    || self.started.elapsed() >= self.connect_timeout
+                {
+                    // Connecting can still work without the relay, e.g. on a LAN
+                    self.state = PeerState::Connecting;
+                    return self.start_attempt();
+                }
+                None
+            },
+            PeerState::Connecting | PeerState::Reconnecting | PeerState::Connected => {
+                let connection = self.connection?;
+                let info = self.sockets.connection_info(connection);
+                let (state, end_reason) = info
+                    .map_or((NetworkingConnectionState::None, NetConnectionEnd::Invalid), |v| (v.state, v.end_reason));
+                match state {
+                    NetworkingConnectionState::Connected => {
+                        if self.state == PeerState::Connected {
+                            return None;
+                        }
+                        self.state = PeerState::Connected;
+                        self.attempt = 0;
+                        Some(PeerEvent::Connected)
+                    },
+                    NetworkingConnectionState::Connecting | NetworkingConnectionState::FindingRoute => {
+                        if self.started.elapsed() < self.connect_timeout {
+                            return None;
+                        }
+                        Some(self.retry(NetConnectionEnd::MiscTimeout))
+                    },
+                    _ => Some(self.retry(end_reason)),
+                }
+            },
+        }
+    }
+
+    fn retry(&mut self, reason: NetConnectionEnd) -> PeerEvent {
+        self.close();
+        if !is_retryable(reason) || self.attempt >= self.max_retries {
+            self.state = PeerState::Failed;
+            return PeerEvent::Failed(reason);
+        }
+        self.attempt += 1;
+        self.state = PeerState::Reconnecting;
+        self.start_attempt().unwrap_or(PeerEvent::Reconnecting {
+            reason,
+            attempt: self.attempt,
+        })
+    }
+
+    fn start_attempt(&mut self) -> Option<PeerEvent> {
+        self.started = Instant::now();
+        self.connection = self.sockets.connect_p2p_with_config(&NetworkingIdentity::SteamId(self.peer), self.virtual_port, &self.config);
+        if self.connection.is_none() {
+            self.state = PeerState::Failed;
+            return Some(PeerEvent::Failed(NetConnectionEnd::MiscGeneric));
+        }
+        None
+    }
+
+    fn close(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.sockets.close_connection(connection, NetConnectionEnd::Invalid, "", false);
+        }
+    }
+}
+
+impl <Manager> Drop for PeerConnection<Manager> {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.sockets.close_connection(connection, NetConnectionEnd::App(1000), "", true);
+        }
+    }
+}
+
+/// Returns whether trying to connect again after the connection
+/// ended for the reason may succeed
+fn is_retryable(reason: NetConnectionEnd) -> bool {
+    match reason {
+        // The peer or the application closed the connection on purpose
+        NetConnectionEnd::App(_) | NetConnectionEnd::AppException(_) => false,
+        NetConnectionEnd::LocalOfflineMode
+        | NetConnectionEnd::LocalRights
+        | NetConnectionEnd::RemoteBadCrypt
+        | NetConnectionEnd::RemoteBadCert
+        | NetConnectionEnd::RemoteNotLoggedIn
+        | NetConnectionEnd::RemoteNotRunningApp
+        | NetConnectionEnd::RemoteBadProtocolVersion => false,
+        _ => true,
+    }
+}
+
+#[test]
+fn retryable_reasons() {
+    assert!(is_retryable(NetConnectionEnd::MiscTimeout));
+    assert!(is_retryable(NetConnectionEnd::RemoteTimeout));
+    assert!(is_retryable(NetConnectionEnd::Invalid));
+    assert!(!is_retryable(NetConnectionEnd::App(1000)));
+    assert!(!is_retryable(NetConnectionEnd::RemoteNotRunningApp));
+    assert!(!is_retryable(NetConnectionEnd::LocalOfflineMode));
+}
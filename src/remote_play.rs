@@ -0,0 +1,174 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 5700;
+
+/// Access to the steam remote play interface
+pub struct RemotePlay<Manager> {
+    pub(crate) remote_play: *mut sys::ISteamRemotePlay,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+/// The id of a remote play session
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RemotePlaySessionId(pub(crate) sys::RemotePlaySessionID_t);
+
+impl RemotePlaySessionId {
+    /// Creates a `RemotePlaySessionId` from a raw 32 bit value.
+    pub fn from_raw(id: u32) -> RemotePlaySessionId {
+        RemotePlaySessionId(id)
+    }
+
+    /// Returns the raw 32 bit value of the session id
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+}
+
+/// The type of device a remote play client is running on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DeviceFormFactor {
+    Unknown,
+    Phone,
+    Tablet,
+    Computer,
+    TV,
+}
+
+impl From<sys::ESteamDeviceFormFactor> for DeviceFormFactor {
+    fn from(form_factor: sys::ESteamDeviceFormFactor) -> DeviceFormFactor {
+        match form_factor {
+            sys::ESteamDeviceFormFactor::k_ESteamDeviceFormFactorPhone => DeviceFormFactor::Phone,
+            sys::ESteamDeviceFormFactor::k_ESteamDeviceFormFactorTablet => DeviceFormFactor::Tablet,
+            sys::ESteamDeviceFormFactor::k_ESteamDeviceFormFactorComputer => DeviceFormFactor::Computer,
+            sys::ESteamDeviceFormFactor::k_ESteamDeviceFormFactorTV => DeviceFormFactor::TV,
+            _ => DeviceFormFactor::Unknown,
+        }
+    }
+}
+
+impl <Manager> RemotePlay<Manager> {
+    /// Returns the raw `ISteamRemotePlay` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamRemotePlay {
+        self.remote_play
+    }
+
+    /// Returns the number of currently connected remote play sessions
+    pub fn session_count(&self) -> u32 {
+        unsafe {
+            sys::SteamAPI_ISteamRemotePlay_GetSessionCount(self.remote_play)
+        }
+    }
+
+    /// Returns the ids of all currently connected remote play sessions
+    pub fn sessions(&self) -> Vec<RemotePlaySessionId> {
+        unsafe {
+            let count = sys::SteamAPI_ISteamRemotePlay_GetSessionCount(self.remote_play);
+            (0 .. count)
+                .map(|idx| sys::SteamAPI_ISteamRemotePlay_GetSessionID(self.remote_play, idx as _))
+                .filter(|&id| id != 0)
+                .map(RemotePlaySessionId)
+                .collect()
+        }
+    }
+
+    /// Returns the steam id of the user connected through the session
+    pub fn session_steam_id(&self, session: RemotePlaySessionId) -> SteamId {
+        unsafe {
+            SteamId(sys::SteamAPI_ISteamRemotePlay_GetSessionSteamID(self.remote_play, session.0))
+        }
+    }
+
+    /// Returns the name of the device the session is connected from
+    ///
+    /// Returns `None` if the session is no longer connected.
+    pub fn session_client_name(&self, session: RemotePlaySessionId) -> Option<String> {
+        unsafe {
+            let name = sys::SteamAPI_ISteamRemotePlay_GetSessionClientName(self.remote_play, session.0);
+            if name.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(name).to_string_lossy().into_owned())
+            }
+        }
+    }
+
+    /// Returns the type of device the session is connected from
+    pub fn session_client_form_factor(&self, session: RemotePlaySessionId) -> DeviceFormFactor {
+        unsafe {
+            sys::SteamAPI_ISteamRemotePlay_GetSessionClientFormFactor(self.remote_play, session.0).into()
+        }
+    }
+
+    /// Returns the resolution in pixels of the device the session is
+    /// connected from
+    pub fn session_client_resolution(&self, session: RemotePlaySessionId) -> Option<(u32, u32)> {
+        unsafe {
+            let mut width = 0;
+            let mut height = 0;
+            if sys::SteamAPI_ISteamRemotePlay_BGetSessionClientResolution(self.remote_play, session.0, &mut width, &mut height) {
+                Some((width as u32, height as u32))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Invites a friend to join the game through remote play together.
+    ///
+    /// Returns false if the invite couldn't be sent.
+    pub fn send_remote_play_together_invite(&self, friend: SteamId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamRemotePlay_BSendRemotePlayTogetherInvite(self.remote_play, friend.0)
+        }
+    }
+}
+
+/// Called when a remote play client connects to the game
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RemotePlaySessionConnected {
+    /// The id of the session that connected
+    pub session: RemotePlaySessionId,
+}
+
+unsafe impl Callback for RemotePlaySessionConnected {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamRemotePlaySessionConnected_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamRemotePlaySessionConnected_t);
+        RemotePlaySessionConnected {
+            session: RemotePlaySessionId(val.m_unSessionID),
+        }
+    }
+}
+
+/// Called when a remote play client disconnects from the game
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RemotePlaySessionDisconnected {
+    /// The id of the session that disconnected
+    pub session: RemotePlaySessionId,
+}
+
+unsafe impl Callback for RemotePlaySessionDisconnected {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamRemotePlaySessionDisconnected_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamRemotePlaySessionDisconnected_t);
+        RemotePlaySessionDisconnected {
+            session: RemotePlaySessionId(val.m_unSessionID),
+        }
+    }
+}
@@ -21,6 +21,19 @@ impl <Manager> Clone for RemoteStorage<Manager> {
 
 
 impl <Manager> RemoteStorage<Manager> {
+    /// Returns the raw `ISteamRemoteStorage` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamRemoteStorage {
+        self.rs
+    }
+
     /// Toggles whether the steam cloud is enabled for the application
     pub fn set_cloud_enabled_for_app(&self, enabled: bool) {
         unsafe {
@@ -82,6 +95,28 @@ impl <Manager> RemoteStorage<Manager> {
             name: CString::new(name).unwrap(),
         }
     }
+
+    /// Opens a steam cloud file for reading.
+    ///
+    /// Shorthand for `file(name).read()`.
+    pub fn open_read(&self, name: &str) -> std::io::Result<SteamFileReader<Manager>> {
+        let file = self.file(name);
+        if !file.exists() {
+            return Err(std::io::ErrorKind::NotFound.into());
+        }
+        Ok(file.read())
+    }
+
+    /// Opens a steam cloud file for writing, replacing its contents.
+    ///
+    /// Shorthand for `file(name).write()`.
+    pub fn open_write(&self, name: &str) -> std::io::Result<SteamFileWriter<Manager>> {
+        let writer = self.file(name).write();
+        if writer.handle == sys::k_UGCFileStreamHandleInvalid {
+            return Err(std::io::ErrorKind::Other.into());
+        }
+        Ok(writer)
+    }
 }
 
 /// A handle for a possible steam cloud file
@@ -131,6 +166,11 @@ impl <Manager> SteamFile<Manager> {
         }
     }
 
+    /// Opens the file for writing, replacing its contents.
+    ///
+    /// The new contents are committed once the writer is closed or
+    /// dropped. Use `SteamFileWriter::close` to find out whether that
+    /// succeeded.
     pub fn write(self) -> SteamFileWriter<Manager> {
         unsafe {
             let handle = sys::SteamAPI_ISteamRemoteStorage_FileWriteStreamOpen(self.rs, self.name.as_ptr());
@@ -141,6 +181,7 @@ impl <Manager> SteamFile<Manager> {
         }
     }
 
+    /// Opens the file for reading
     pub fn read(self) -> SteamFileReader<Manager> {
         unsafe {
             SteamFileReader {
@@ -157,8 +198,38 @@ pub struct SteamFileWriter<Manager> {
     handle: sys::UGCFileWriteStreamHandle_t,
 }
 
+impl <Manager> SteamFileWriter<Manager> {
+    /// Closes the stream and commits the written data to the file
+    pub fn close(mut self) -> std::io::Result<()> {
+        let handle = std::mem::replace(&mut self.handle, sys::k_UGCFileStreamHandleInvalid);
+        if handle == sys::k_UGCFileStreamHandleInvalid {
+            return Err(std::io::ErrorKind::Other.into());
+        }
+        unsafe {
+            if sys::SteamAPI_ISteamRemoteStorage_FileWriteStreamClose(self.file.rs, handle) {
+                Ok(())
+            } else {
+                Err(std::io::ErrorKind::Other.into())
+            }
+        }
+    }
+
+    /// Discards the written data, leaving the file unchanged
+    pub fn cancel(mut self) {
+        let handle = std::mem::replace(&mut self.handle, sys::k_UGCFileStreamHandleInvalid);
+        if handle != sys::k_UGCFileStreamHandleInvalid {
+            unsafe {
+                sys::SteamAPI_ISteamRemoteStorage_FileWriteStreamCancel(self.file.rs, handle);
+            }
+        }
+    }
+}
+
 impl <Manager> std::io::Write for SteamFileWriter<Manager> {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.handle == sys::k_UGCFileStreamHandleInvalid {
+            return Err(std::io::ErrorKind::Other.into());
+        }
         unsafe {
             if sys::SteamAPI_ISteamRemoteStorage_FileWriteStreamWriteChunk(self.file.rs, self.handle, buf.as_ptr() as *const _, buf.len() as _) {
                 Ok(buf.len())
@@ -175,8 +246,10 @@ impl <Manager> std::io::Write for SteamFileWriter<Manager> {
 
 impl <Manager> Drop for SteamFileWriter<Manager> {
     fn drop(&mut self) {
-        unsafe {
-            sys::SteamAPI_ISteamRemoteStorage_FileWriteStreamClose(self.file.rs, self.handle);
+        if self.handle != sys::k_UGCFileStreamHandleInvalid {
+            unsafe {
+                sys::SteamAPI_ISteamRemoteStorage_FileWriteStreamClose(self.file.rs, self.handle);
+            }
         }
     }
 }
@@ -223,32 +296,41 @@ impl <Manager> std::io::Read for SteamFileReader<Manager> {
     }
 }
 
+impl <Manager> SteamFileReader<Manager> {
+    /// Returns the total size of the file in bytes
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns whether the file is empty
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+}
+
 impl <Manager> std::io::Seek for SteamFileReader<Manager> {
     fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
-        match pos {
-            std::io::SeekFrom::Current(o) => {
-                if self.offset as isize + o as isize >= self.size as isize {
-                    return Err(std::io::ErrorKind::InvalidInput.into());
-                }
-                self.offset = (self.offset as isize + o as isize) as usize;
-            },
-            std::io::SeekFrom::End(o) => {
-                if o as isize >= self.size as isize {
-                    return Err(std::io::ErrorKind::InvalidInput.into());
-                }
-                self.offset = (self.size as isize - 1 - o as isize) as usize;
-            },
-            std::io::SeekFrom::Start(o) => {
-                if o as usize >= self.size {
-                    return Err(std::io::ErrorKind::InvalidInput.into());
-                }
-                self.offset = o as usize;
-            },
-        }
+        self.offset = seek_offset(self.offset, self.size, pos)?;
         Ok(self.offset as u64)
     }
 }
 
+/// Resolves a seek within a file of `size` bytes.
+///
+/// Seeking past the end of the file isn't supported as steam can't
+/// read from there.
+fn seek_offset(offset: usize, size: usize, pos: std::io::SeekFrom) -> std::io::Result<usize> {
+    let target = match pos {
+        std::io::SeekFrom::Start(o) => o as i64,
+        std::io::SeekFrom::Current(o) => offset as i64 + o,
+        std::io::SeekFrom::End(o) => size as i64 + o,
+    };
+    if target < 0 || target > size as i64 {
+        return Err(std::io::ErrorKind::InvalidInput.into());
+    }
+    Ok(target as usize)
+}
+
 /// Name and size information about a file in the steam cloud
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -259,6 +341,17 @@ pub struct SteamFileInfo {
     pub size: u64,
 }
 
+#[test]
+fn seek() {
+    use std::io::SeekFrom;
+    assert_eq!(seek_offset(0, 10, SeekFrom::Start(4)).unwrap(), 4);
+    assert_eq!(seek_offset(4, 10, SeekFrom::Current(-2)).unwrap(), 2);
+    assert_eq!(seek_offset(4, 10, SeekFrom::End(0)).unwrap(), 10);
+    assert_eq!(seek_offset(4, 10, SeekFrom::End(-3)).unwrap(), 7);
+    assert!(seek_offset(4, 10, SeekFrom::Current(-5)).is_err());
+    assert!(seek_offset(4, 10, SeekFrom::Start(11)).is_err());
+}
+
 #[test]
 #[serial]
 fn test_cloud() {
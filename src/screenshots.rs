@@ -0,0 +1,196 @@
+use super::*;
+use std::path::Path;
+
+const CALLBACK_BASE_ID: i32 = 2300;
+
+/// Access to the steam screenshots interface
+pub struct Screenshots<Manager> {
+    pub(crate) screenshots: *mut sys::ISteamScreenshots,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+/// A handle to a screenshot in the user's screenshot library
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScreenshotHandle(pub(crate) sys::ScreenshotHandle);
+
+impl ScreenshotHandle {
+    /// Returns the raw 32 bit value of the handle
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+}
+
+impl <Manager> Screenshots<Manager> {
+    /// Returns the raw `ISteamScreenshots` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamScreenshots {
+        self.screenshots
+    }
+
+    /// Takes a screenshot as if the user pressed the screenshot key.
+    ///
+    /// The screenshot is written to the user's library and a
+    /// `ScreenshotReady` callback is fired once it has been saved.
+    pub fn trigger_screenshot(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamScreenshots_TriggerScreenshot(self.screenshots);
+        }
+    }
+
+    /// Toggles whether the game handles screenshots itself.
+    ///
+    /// When hooked, pressing the screenshot key fires a
+    /// `ScreenshotRequested` callback instead of steam capturing the
+    /// screen, and the game is expected to supply the image with
+    /// `write_screenshot` or `add_screenshot_to_library`.
+    pub fn hook_screenshots(&self, hook: bool) {
+        unsafe {
+            sys::SteamAPI_ISteamScreenshots_HookScreenshots(self.screenshots, hook);
+        }
+    }
+
+    /// Returns whether screenshots are currently hooked by the game
+    pub fn is_screenshots_hooked(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamScreenshots_IsScreenshotsHooked(self.screenshots)
+        }
+    }
+
+    /// Writes a screenshot to the user's library from raw RGB data.
+    ///
+    /// `rgb` must contain exactly `width * height` tightly packed RGB
+    /// pixels. A `ScreenshotReady` callback is fired once the screenshot
+    /// has been saved.
+    ///
+    /// Fails with `SteamError::InvalidParameter` if `rgb` has the wrong
+    /// size and with `SteamError::Generic` if steam couldn't write the
+    /// screenshot.
+    pub fn write_screenshot(&self, rgb: &[u8], width: u32, height: u32) -> SResult<ScreenshotHandle> {
+        let expected = (width as usize).checked_mul(height as usize)
+            .and_then(|v| v.checked_mul(3));
+        if expected != Some(rgb.len()) {
+            return Err(SteamError::InvalidParameter);
+        }
+        unsafe {
+            let handle = sys::SteamAPI_ISteamScreenshots_WriteScreenshot(
+                self.screenshots,
+                rgb.as_ptr() as *mut _, rgb.len() as _,
+                width as _, height as _
+            );
+            if handle == 0 {
+                Err(SteamError::Generic)
+            } else {
+                Ok(ScreenshotHandle(handle))
+            }
+        }
+    }
+
+    /// Adds an existing image file on disk to the user's library.
+    ///
+    /// The file must be a JPEG, TGA or PNG. If no thumbnail is provided
+    /// one will be generated from the image. A `ScreenshotReady`
+    /// callback is fired once the screenshot has been saved.
+    ///
+    /// Returns `None` if the screenshot couldn't be added.
+    pub fn add_screenshot_to_library(&self, path: &Path, thumbnail: Option<&Path>, width: u32, height: u32) -> Option<ScreenshotHandle> {
+        let path = CString::new(&*path.to_string_lossy()).unwrap();
+        let thumbnail = thumbnail.map(|v| CString::new(&*v.to_string_lossy()).unwrap());
+        unsafe {
+            let handle = sys::SteamAPI_ISteamScreenshots_AddScreenshotToLibrary(
+                self.screenshots,
+                path.as_ptr(),
+                thumbnail.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                width as _, height as _
+            );
+            if handle == 0 {
+                None
+            } else {
+                Some(ScreenshotHandle(handle))
+            }
+        }
+    }
+
+    /// Sets the location the screenshot was taken at, shown in the
+    /// screenshot manager and community hub.
+    ///
+    /// The location must be shorter than 255 bytes. Returns false if
+    /// the handle is invalid or the location is too long.
+    pub fn set_location(&self, screenshot: ScreenshotHandle, location: &str) -> bool {
+        if location.len() >= sys::k_cubUFSTagValueMax as usize {
+            return false;
+        }
+        let location = CString::new(location).unwrap();
+        unsafe {
+            sys::SteamAPI_ISteamScreenshots_SetLocation(self.screenshots, screenshot.0, location.as_ptr())
+        }
+    }
+
+    /// Tags a user as being visible in the screenshot.
+    ///
+    /// Up to 32 users can be tagged in a single screenshot.
+    /// Returns false if the handle is invalid or too many users have
+    /// been tagged.
+    pub fn tag_user(&self, screenshot: ScreenshotHandle, user: SteamId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamScreenshots_TagUser(self.screenshots, screenshot.0, user.0)
+        }
+    }
+
+    /// Tags a workshop item as being visible in the screenshot.
+    ///
+    /// Up to 32 items can be tagged in a single screenshot.
+    /// Returns false if the handle is invalid or too many items have
+    /// been tagged.
+    pub fn tag_published_file(&self, screenshot: ScreenshotHandle, file: PublishedFileId) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamScreenshots_TagPublishedFile(self.screenshots, screenshot.0, file.0)
+        }
+    }
+}
+
+/// Called when a screenshot has been saved to the user's library
+#[derive(Clone, Debug)]
+pub struct ScreenshotReady {
+    /// The handle of the screenshot that was saved
+    pub handle: ScreenshotHandle,
+    /// Whether the screenshot was saved successfully
+    pub result: SResult<()>,
+}
+
+unsafe impl Callback for ScreenshotReady {
+    const ID: i32 = CALLBACK_BASE_ID + 1;
+    const SIZE: i32 = ::std::mem::size_of::<sys::ScreenshotReady_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::ScreenshotReady_t);
+        ScreenshotReady {
+            handle: ScreenshotHandle(val.m_hLocal),
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+        }
+    }
+}
+
+/// Called when the user requests a screenshot while screenshots
+/// are hooked with `hook_screenshots`.
+#[derive(Clone, Debug)]
+pub struct ScreenshotRequested;
+
+unsafe impl Callback for ScreenshotRequested {
+    const ID: i32 = CALLBACK_BASE_ID + 2;
+    const SIZE: i32 = ::std::mem::size_of::<sys::ScreenshotRequested_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        ScreenshotRequested
+    }
+}
@@ -1,5 +1,10 @@
+mod auth;
+mod stats;
+
+pub use self::auth::*;
+pub use self::stats::*;
 use super::*;
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 #[cfg(test)]
 use serial_test_derive::serial;
 
@@ -35,6 +40,19 @@ pub enum ServerMode {
 }
 
 impl Server {
+    /// Returns the raw `ISteamGameServer` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamGameServer {
+        self.server
+    }
+
     /// Attempts to initialize the steamworks api and returns
     /// a server to access the rest of the api.
     ///
@@ -82,6 +100,8 @@ impl Server {
                     callbacks: HashMap::new(),
                     call_results: HashMap::new(),
                 }),
+                #[cfg(feature = "metrics")]
+                stats: Mutex::new(CallbackStats::default()),
             });
             Ok((Server {
                 inner: server.clone(),
@@ -93,11 +113,30 @@ impl Server {
         }
     }
 
+    /// Returns a snapshot of the callback dispatch stats collected
+    /// so far.
+    ///
+    /// Only available with the `metrics` feature.
+    #[cfg(feature = "metrics")]
+    pub fn callback_stats(&self) -> CallbackStats {
+        self.inner.stats.lock().unwrap().clone()
+    }
+
+    /// Clears the callback dispatch stats collected so far.
+    ///
+    /// Only available with the `metrics` feature.
+    #[cfg(feature = "metrics")]
+    pub fn reset_callback_stats(&self) {
+        *self.inner.stats.lock().unwrap() = CallbackStats::default();
+    }
+
     /// Registers the passed function as a callback for the
     /// given type.
     ///
     /// The callback will be run on the thread that `run_callbacks`
-    /// is called when the event arrives.
+    /// is called when the event arrives. Several callbacks can be
+    /// registered for the same type, they are run in the order they
+    /// were registered.
     pub fn register_callback<C, F>(&self, f: F) -> CallbackHandle<ServerManager>
         where C: Callback,
               F: FnMut(C) + 'static + Send
@@ -123,15 +162,26 @@ impl Server {
     /// for the `AuthSessionTicketResponse` event before trying to
     /// use the ticket.
     ///
-    /// When the multiplayer session terminates you must call
-    /// `cancel_authentication_ticket`
-    pub fn authentication_session_ticket(&self) -> (AuthTicket, Vec<u8>) {
+    /// The ticket is cancelled when the returned `AuthTicket` is
+    /// dropped, which should happen once the multiplayer session
+    /// terminates.
+    ///
+    /// Fails with `SteamError::Generic` if steam couldn't create
+    /// a ticket.
+    pub fn authentication_session_ticket(&self) -> SResult<(AuthTicket<ServerManager>, Vec<u8>)> {
         unsafe {
             let mut ticket = vec![0; 1024];
             let mut ticket_len = 0;
             let auth_ticket = sys::SteamAPI_ISteamGameServer_GetAuthSessionTicket(self.server, ticket.as_mut_ptr() as *mut _, 1024, &mut ticket_len);
+            if auth_ticket == sys::k_HAuthTicketInvalid {
+                return Err(SteamError::Generic);
+            }
             ticket.truncate(ticket_len as usize);
-            (AuthTicket(auth_ticket), ticket)
+            Ok((AuthTicket {
+                handle: AuthTicketHandle(auth_ticket),
+                source: TicketSource::Server(self.server),
+                _inner: self.inner.clone(),
+            }, ticket))
         }
     }
 
@@ -139,11 +189,9 @@ impl Server {
     /// `authentication_session_ticket`.
     ///
     /// This should be called when you are no longer playing with
-    /// the specified entity.
-    pub fn cancel_authentication_ticket(&self, ticket: AuthTicket) {
-        unsafe {
-            sys::SteamAPI_ISteamGameServer_CancelAuthTicket(self.server, ticket.0);
-        }
+    /// the specified entity. It is the same as dropping the ticket.
+    pub fn cancel_authentication_ticket(&self, ticket: AuthTicket<ServerManager>) {
+        drop(ticket);
     }
 
     /// Authenticate the ticket from the steam ID to make sure it is
@@ -184,6 +232,17 @@ impl Server {
         }
     }
 
+    /// Checks whether a user with an authentication session owns
+    /// a license for the app, e.g. a dlc.
+    ///
+    /// Returns `UserHasLicense::NoAuth` if the user's session hasn't
+    /// been started with `begin_authentication_session`.
+    pub fn user_has_license_for_app(&self, user: SteamId, app_id: AppId) -> UserHasLicense {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_UserHasLicenseForApp(self.server, user.0, app_id.0).into()
+        }
+    }
+
     /// Sets the game product identifier. This is currently used by the master server for version
     /// checking purposes. Converting the games app ID to a string for this is recommended.
     ///
@@ -229,6 +288,64 @@ impl Server {
         }
     }
 
+    /// Sets how often heartbeats are sent to the master server in
+    /// milliseconds.
+    ///
+    /// Passing `-1` restores the default interval. This should
+    /// usually be left alone.
+    pub fn set_heartbeat_interval(&self, interval_ms: i32) {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_SetHeartbeatInterval(self.server, interval_ms);
+        }
+    }
+
+    /// Sends a heartbeat to the master server immediately.
+    ///
+    /// Useful after changing the server's details so they show up
+    /// in the server browser sooner.
+    pub fn force_heartbeat(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_ForceHeartbeat(self.server);
+        }
+    }
+
+    /// Returns whether the server is logged on to steam
+    pub fn is_logged_on(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_BLoggedOn(self.server)
+        }
+    }
+
+    /// Returns whether the server is VAC secured.
+    ///
+    /// This is only valid once the server has connected to steam
+    /// and received a `GSPolicyResponse`.
+    pub fn is_secure(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamGameServer_BSecure(self.server)
+        }
+    }
+
+    /// Returns the public ip of the server as seen by steam.
+    ///
+    /// Returns `None` if the server isn't logged on yet.
+    pub fn public_ip(&self) -> Option<IpAddr> {
+        unsafe {
+            let ip = sys::SteamAPI_ISteamGameServer_GetPublicIP(self.server);
+            let addr = ip.__bindgen_anon_1;
+            let ip = match ip.m_eType {
+                sys::ESteamIPType::k_ESteamIPTypeIPv4 => IpAddr::V4(Ipv4Addr::from(addr.m_unIPv4)),
+                sys::ESteamIPType::k_ESteamIPTypeIPv6 => IpAddr::V6(Ipv6Addr::from(addr.m_rgubIPv6)),
+                _ => return None,
+            };
+            if ip.is_unspecified() {
+                None
+            } else {
+                Some(ip)
+            }
+        }
+    }
+
     /// If your game is a "mod," pass the string that identifies it.  The default is an empty
     /// string, meaning this application is the original game, not a mod.
     pub fn set_mod_dir(&self, mod_dir: &str) {
@@ -264,6 +381,48 @@ impl Server {
         }
     }
 
+    /// Returns an accessor to the steam inventory interface
+    /// for game servers.
+    ///
+    /// This is mainly useful for verifying items with
+    /// `Inventory::deserialize_result`.
+    pub fn inventory(&self) -> Inventory<ServerManager> {
+        unsafe {
+            let inventory = sys::SteamAPI_SteamGameServerInventory_v003();
+            debug_assert!(!inventory.is_null());
+            Inventory {
+                inventory,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the stats and achievements of the
+    /// players on the server
+    pub fn stats(&self) -> GameServerStats {
+        unsafe {
+            let stats = sys::SteamAPI_SteamGameServerStats_v001();
+            debug_assert!(!stats.is_null());
+            GameServerStats {
+                stats,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    /// Returns an accessor to the steam networking sockets interface
+    /// for game servers.
+    pub fn networking_sockets(&self) -> NetworkingSockets<ServerManager> {
+        unsafe {
+            let sockets = sys::SteamAPI_SteamGameServerNetworkingSockets_v008();
+            debug_assert!(!sockets.is_null());
+            NetworkingSockets {
+                sockets,
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
     /* TODO: Buggy currently?
     /// Returns an accessor to the steam apps interface
     pub fn apps(&self) -> Apps<ServerManager> {
@@ -279,6 +438,160 @@ impl Server {
     */
 }
 
+/// The reason a client was denied or kicked from a game server
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DenyReason {
+    /// An invalid or unknown reason
+    Invalid,
+    /// The client's version of the game doesn't match the server
+    InvalidVersion,
+    /// A generic denial
+    Generic,
+    /// The client isn't logged on to steam
+    NotLoggedOn,
+    /// The client doesn't own a license for the game
+    NoLicense,
+    /// The client is VAC banned
+    Cheater,
+    /// The account is logged in on another machine
+    LoggedInElseWhere,
+    /// The reason was given as text by the server
+    UnknownText,
+    /// The client's anti-cheat is incompatible
+    IncompatibleAnticheat,
+    /// Memory corruption was detected on the client
+    MemoryCorruption,
+    /// Software incompatible with the game is running on the client
+    IncompatibleSoftware,
+    /// The connection to steam was lost
+    SteamConnectionLost,
+    /// An error occurred in the connection to steam
+    SteamConnectionError,
+    /// Steam didn't respond in time while validating the client
+    SteamResponseTimedOut,
+    /// Validating the client with steam stalled
+    SteamValidationStalled,
+    /// The owner of the shared license the client was using left
+    SteamOwnerLeftGuestUser,
+}
+
+impl From<sys::EDenyReason> for DenyReason {
+    fn from(reason: sys::EDenyReason) -> DenyReason {
+        match reason {
+            sys::EDenyReason::k_EDenyInvalidVersion => DenyReason::InvalidVersion,
+            sys::EDenyReason::k_EDenyGeneric => DenyReason::Generic,
+            sys::EDenyReason::k_EDenyNotLoggedOn => DenyReason::NotLoggedOn,
+            sys::EDenyReason::k_EDenyNoLicense => DenyReason::NoLicense,
+            sys::EDenyReason::k_EDenyCheater => DenyReason::Cheater,
+            sys::EDenyReason::k_EDenyLoggedInElseWhere => DenyReason::LoggedInElseWhere,
+            sys::EDenyReason::k_EDenyUnknownText => DenyReason::UnknownText,
+            sys::EDenyReason::k_EDenyIncompatibleAnticheat => DenyReason::IncompatibleAnticheat,
+            sys::EDenyReason::k_EDenyMemoryCorruption => DenyReason::MemoryCorruption,
+            sys::EDenyReason::k_EDenyIncompatibleSoftware => DenyReason::IncompatibleSoftware,
+            sys::EDenyReason::k_EDenySteamConnectionLost => DenyReason::SteamConnectionLost,
+            sys::EDenyReason::k_EDenySteamConnectionError => DenyReason::SteamConnectionError,
+            sys::EDenyReason::k_EDenySteamResponseTimedOut => DenyReason::SteamResponseTimedOut,
+            sys::EDenyReason::k_EDenySteamValidationStalled => DenyReason::SteamValidationStalled,
+            sys::EDenyReason::k_EDenySteamOwnerLeftGuestUser => DenyReason::SteamOwnerLeftGuestUser,
+            _ => DenyReason::Invalid,
+        }
+    }
+}
+
+/// Called when the server receives its VAC policy from steam
+/// after logging on.
+#[derive(Clone, Debug)]
+pub struct GSPolicyResponse {
+    /// Whether the server is VAC secured
+    pub secure: bool,
+}
+
+unsafe impl Callback for GSPolicyResponse {
+    const ID: i32 = 115;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GSPolicyResponse_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GSPolicyResponse_t);
+        GSPolicyResponse {
+            secure: val.m_bSecure != 0,
+        }
+    }
+}
+
+/// Called when a client has been approved to connect to
+/// the server
+#[derive(Clone, Debug)]
+pub struct GSClientApprove {
+    /// The steam id of the client
+    pub steam_id: SteamId,
+    /// The steam id of the owner of the game. Differs from
+    /// `steam_id` if the game is borrowed.
+    pub owner_steam_id: SteamId,
+}
+
+unsafe impl Callback for GSClientApprove {
+    const ID: i32 = 201;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GSClientApprove_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GSClientApprove_t);
+        GSClientApprove {
+            steam_id: SteamId(val.m_SteamID.m_steamid.m_unAll64Bits),
+            owner_steam_id: SteamId(val.m_OwnerSteamID.m_steamid.m_unAll64Bits),
+        }
+    }
+}
+
+/// Called when a client has been denied from connecting to
+/// the server
+#[derive(Clone, Debug)]
+pub struct GSClientDeny {
+    /// The steam id of the client
+    pub steam_id: SteamId,
+    /// The reason the client was denied
+    pub reason: DenyReason,
+    /// Optional text explaining the reason, may be empty
+    pub optional_text: String,
+}
+
+unsafe impl Callback for GSClientDeny {
+    const ID: i32 = 202;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GSClientDeny_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GSClientDeny_t);
+        GSClientDeny {
+            steam_id: SteamId(val.m_SteamID.m_steamid.m_unAll64Bits),
+            reason: val.m_eDenyReason.into(),
+            optional_text: CStr::from_ptr(val.m_rgchOptionalText.as_ptr()).to_string_lossy().into_owned(),
+        }
+    }
+}
+
+/// Called when steam requests that a client be kicked from
+/// the server, e.g. after a VAC ban.
+#[derive(Clone, Debug)]
+pub struct GSClientKick {
+    /// The steam id of the client to kick
+    pub steam_id: SteamId,
+    /// The reason the client should be kicked
+    pub reason: DenyReason,
+}
+
+unsafe impl Callback for GSClientKick {
+    const ID: i32 = 203;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GSClientKick_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GSClientKick_t);
+        GSClientKick {
+            steam_id: SteamId(val.m_SteamID.m_steamid.m_unAll64Bits),
+            reason: val.m_eDenyReason.into(),
+        }
+    }
+}
+
 #[test]
 #[serial]
 fn test() {
@@ -301,7 +614,7 @@ fn test() {
     let _cb = server.register_callback(|v: ValidateAuthTicketResponse| println!("{:?}", v));
 
     let id = server.steam_id();
-    let (auth, ticket) = server.authentication_session_ticket();
+    let (auth, ticket) = server.authentication_session_ticket().unwrap();
 
     println!("{:?}", server.begin_authentication_session(id, &ticket));
 
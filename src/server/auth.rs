@@ -0,0 +1,230 @@
+use super::*;
+use std::collections::{HashMap, VecDeque};
+
+/// The authentication state of a player tracked by `PlayerAuth`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlayerAuthState {
+    /// The ticket is waiting to be validated by steam
+    Pending,
+    /// The ticket is valid and the player owns the required apps
+    Authorized,
+    /// The player was rejected
+    Rejected,
+}
+
+/// Why a player was rejected by `PlayerAuth`
+#[derive(Debug)]
+pub enum AuthRejection {
+    /// The ticket couldn't be checked at all
+    Ticket(AuthSessionError),
+    /// Steam rejected the ticket, either straight away or later on
+    /// during the session
+    Validation(AuthSessionValidateError),
+    /// The player doesn't own a license for a required app
+    NoLicense(AppId),
+}
+
+/// Events produced by `PlayerAuth::poll`
+#[derive(Debug)]
+pub enum PlayerAuthEvent {
+    /// The player's ticket was validated and they own every
+    /// required app
+    Authorized {
+        steam_id: SteamId,
+        /// The owner of the game, which differs from `steam_id` if
+        /// the game is borrowed through family sharing
+        owner_steam_id: SteamId,
+    },
+    /// The player was rejected and should be removed from the server
+    Rejected {
+        steam_id: SteamId,
+        reason: AuthRejection,
+    },
+}
+
+struct Player {
+    state: PlayerAuthState,
+    owner: Option<SteamId>,
+    vac_banned: bool,
+    publisher_banned: bool,
+}
+
+/// Tracks the authentication of the players on a server.
+///
+/// Wraps `Server::begin_authentication_session`, the
+/// `ValidateAuthTicketResponse` callback and license checks. Each
+/// player produces a single `Authorized` or `Rejected` event from
+/// `poll`. A player that was authorized can still be rejected later
+/// in the session, e.g. when they are VAC banned or cancel their
+/// ticket, which produces a `Rejected` event after the `Authorized` one.
+///
+/// Sessions of rejected players are ended automatically. All sessions
+/// are ended when this is dropped.
+pub struct PlayerAuth {
+    server: Server,
+    required_apps: Vec<AppId>,
+    players: HashMap<SteamId, Player>,
+
+    responses: Arc<Mutex<VecDeque<ValidateAuthTicketResponse>>>,
+    events: VecDeque<PlayerAuthEvent>,
+    _callback: CallbackHandle<ServerManager>,
+}
+
+impl PlayerAuth {
+    /// Creates a tracker with no players
+    pub fn new(server: &Server) -> PlayerAuth {
+        let responses = Arc::new(Mutex::new(VecDeque::new()));
+        let queue = Arc::clone(&responses);
+        let callback = server.register_callback(move |v: ValidateAuthTicketResponse| {
+            queue.lock().unwrap().push_back(v);
+        });
+        PlayerAuth {
+            server: server.clone(),
+            required_apps: Vec::new(),
+            players: HashMap::new(),
+
+            responses,
+            events: VecDeque::new(),
+            _callback: callback,
+        }
+    }
+
+    /// Requires players to own a license for the app, e.g. the game
+    /// itself or a dlc, before they are authorized
+    pub fn require_license(mut self, app_id: AppId) -> Self {
+        self.required_apps.push(app_id);
+        self
+    }
+
+    /// Starts authenticating a player with the ticket they sent.
+    ///
+    /// The result is reported later by `poll`. Does nothing if the
+    /// player is already being tracked.
+    pub fn begin(&mut self, steam_id: SteamId, ticket: &[u8]) {
+        if self.players.contains_key(&steam_id) {
+            return;
+        }
+        let mut player = Player {
+            state: PlayerAuthState::Pending,
+            owner: None,
+            vac_banned: false,
+            publisher_banned: false,
+        };
+        if let Err(err) = self.server.begin_authentication_session(steam_id, ticket) {
+            player.state = PlayerAuthState::Rejected;
+            self.events.push_back(PlayerAuthEvent::Rejected {
+                steam_id,
+                reason: AuthRejection::Ticket(err),
+            });
+        }
+        self.players.insert(steam_id, player);
+    }
+
+    /// Stops tracking a player, ending their session if it is
+    /// still running.
+    ///
+    /// This should be called when the player leaves the server.
+    pub fn end(&mut self, steam_id: SteamId) {
+        if let Some(player) = self.players.remove(&steam_id) {
+            if player.state != PlayerAuthState::Rejected {
+                self.server.end_authentication_session(steam_id);
+            }
+        }
+    }
+
+    /// Returns the state of a player, or `None` if the player isn't
+    /// being tracked
+    pub fn state(&self, steam_id: SteamId) -> Option<PlayerAuthState> {
+        self.players.get(&steam_id).map(|v| v.state)
+    }
+
+    /// Returns the owner of the player's copy of the game once their
+    /// ticket has been validated
+    pub fn owner(&self, steam_id: SteamId) -> Option<SteamId> {
+        self.players.get(&steam_id).and_then(|v| v.owner)
+    }
+
+    /// Returns whether steam reported the player as VAC banned
+    pub fn is_vac_banned(&self, steam_id: SteamId) -> bool {
+        self.players.get(&steam_id).map_or(false, |v| v.vac_banned)
+    }
+
+    /// Returns whether steam reported the player as banned by the
+    /// publisher
+    pub fn is_publisher_banned(&self, steam_id: SteamId) -> bool {
+        self.players.get(&steam_id).map_or(false, |v| v.publisher_banned)
+    }
+
+    /// Checks whether an authorized player owns a license for the app.
+    ///
+    /// Useful for dlc that doesn't need to be required to join.
+    pub fn has_license(&self, steam_id: SteamId, app_id: AppId) -> bool {
+        self.state(steam_id) == Some(PlayerAuthState::Authorized)
+            && self.server.user_has_license_for_app(steam_id, app_id) == UserHasLicense::HasLicense
+    }
+
+    /// Returns the next authentication event if there is one.
+    ///
+    /// This should be called until it returns `None` after running
+    /// callbacks.
+    pub fn poll(&mut self) -> Option<PlayerAuthEvent> {
+        if self.events.is_empty() {
+            let responses = std::mem::take(&mut *self.responses.lock().unwrap());
+            for response in responses {
+                self.process_response(response);
+            }
+        }
+        self.events.pop_front()
+    }
+
+    fn process_response(&mut self, response: ValidateAuthTicketResponse) {
+        let steam_id = response.steam_id;
+        let player = match self.players.get_mut(&steam_id) {
+            Some(player) if player.state != PlayerAuthState::Rejected => player,
+            _ => return,
+        };
+        player.owner = Some(response.owner_steam_id);
+
+        let reason = match response.response {
+            Ok(()) if player.state == PlayerAuthState::Authorized => return,
+            Ok(()) => {
+                let server = &self.server;
+                let missing = self.required_apps.iter()
+                    .find(|&&app_id| server.user_has_license_for_app(steam_id, app_id) != UserHasLicense::HasLicense);
+                match missing {
+                    Some(&app_id) => AuthRejection::NoLicense(app_id),
+                    None => {
+                        player.state = PlayerAuthState::Authorized;
+                        self.events.push_back(PlayerAuthEvent::Authorized {
+                            steam_id,
+                            owner_steam_id: response.owner_steam_id,
+                        });
+                        return;
+                    },
+                }
+            },
+            Err(err) => {
+                match err {
+                    AuthSessionValidateError::VACBanned => player.vac_banned = true,
+                    AuthSessionValidateError::PublisherIssuedBan => player.publisher_banned = true,
+                    _ => {},
+                }
+                AuthRejection::Validation(err)
+            },
+        };
+        player.state = PlayerAuthState::Rejected;
+        self.server.end_authentication_session(steam_id);
+        self.events.push_back(PlayerAuthEvent::Rejected { steam_id, reason });
+    }
+}
+
+impl Drop for PlayerAuth {
+    fn drop(&mut self) {
+        for (&steam_id, player) in &self.players {
+            if player.state != PlayerAuthState::Rejected {
+                self.server.end_authentication_session(steam_id);
+            }
+        }
+    }
+}
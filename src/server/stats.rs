@@ -0,0 +1,208 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 1800;
+
+/// Access to the stats and achievements of the players on a
+/// game server.
+///
+/// The server is the authority for the stats it writes. A player's
+/// stats must be requested with `request_user_stats` before they can
+/// be read or written, and changes are sent to steam with
+/// `store_user_stats`.
+pub struct GameServerStats {
+    pub(crate) stats: *mut sys::ISteamGameServerStats,
+    pub(crate) inner: Arc<Inner<ServerManager>>,
+}
+
+impl GameServerStats {
+    /// Returns the raw `ISteamGameServerStats` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamGameServerStats {
+        self.stats
+    }
+
+    /// Requests the stats and achievements of a player, calling
+    /// the callback once they have been received
+    pub fn request_user_stats<F>(&self, user: SteamId, mut cb: F)
+        where F: FnMut(GSStatsReceived) + 'static + Send
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamGameServerStats_RequestUserStats(self.stats, user.0);
+            register_call_result::<sys::GSStatsReceived_t, _, _>(
+                &self.inner, api_call, CALLBACK_BASE_ID,
+                move |v, io_error| {
+                    cb(GSStatsReceived {
+                        steam_id: user,
+                        result: if io_error {
+                            Err(SteamError::IOFailure)
+                        } else if v.m_eResult != sys::EResult::k_EResultOK {
+                            Err(v.m_eResult.into())
+                        } else {
+                            Ok(())
+                        },
+                    })
+                });
+        }
+    }
+
+    /// Sends the changed stats and achievements of a player to steam,
+    /// calling the callback once they have been stored.
+    ///
+    /// A failed store can be retried by calling this again.
+    pub fn store_user_stats<F>(&self, user: SteamId, mut cb: F)
+        where F: FnMut(GSStatsStored) + 'static + Send
+    {
+        unsafe {
+            let api_call = sys::SteamAPI_ISteamGameServerStats_StoreUserStats(self.stats, user.0);
+            register_call_result::<sys::GSStatsStored_t, _, _>(
+                &self.inner, api_call, CALLBACK_BASE_ID + 1,
+                move |v, io_error| {
+                    cb(GSStatsStored {
+                        steam_id: user,
+                        result: if io_error {
+                            Err(SteamError::IOFailure)
+                        } else if v.m_eResult != sys::EResult::k_EResultOK {
+                            Err(v.m_eResult.into())
+                        } else {
+                            Ok(())
+                        },
+                    })
+                });
+        }
+    }
+
+    /// Returns the value of a player's integer stat
+    pub fn user_stat_i32(&self, user: SteamId, name: &str) -> Result<i32, ()> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            let mut value = 0;
+            if sys::SteamAPI_ISteamGameServerStats_GetUserStatInt32(self.stats, user.0, name.as_ptr(), &mut value) {
+                Ok(value)
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    /// Returns the value of a player's float stat
+    pub fn user_stat_f32(&self, user: SteamId, name: &str) -> Result<f32, ()> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            let mut value = 0.0;
+            if sys::SteamAPI_ISteamGameServerStats_GetUserStatFloat(self.stats, user.0, name.as_ptr(), &mut value) {
+                Ok(value)
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    /// Sets the value of a player's integer stat
+    pub fn set_user_stat_i32(&self, user: SteamId, name: &str, value: i32) -> Result<(), ()> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            if sys::SteamAPI_ISteamGameServerStats_SetUserStatInt32(self.stats, user.0, name.as_ptr(), value) {
+                Ok(())
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    /// Sets the value of a player's float stat
+    pub fn set_user_stat_f32(&self, user: SteamId, name: &str, value: f32) -> Result<(), ()> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            if sys::SteamAPI_ISteamGameServerStats_SetUserStatFloat(self.stats, user.0, name.as_ptr(), value) {
+                Ok(())
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    /// Returns whether a player has unlocked an achievement
+    pub fn user_achievement(&self, user: SteamId, name: &str) -> Result<bool, ()> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            let mut achieved = false;
+            if sys::SteamAPI_ISteamGameServerStats_GetUserAchievement(self.stats, user.0, name.as_ptr(), &mut achieved) {
+                Ok(achieved)
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    /// Unlocks an achievement for a player
+    pub fn set_user_achievement(&self, user: SteamId, name: &str) -> Result<(), ()> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            if sys::SteamAPI_ISteamGameServerStats_SetUserAchievement(self.stats, user.0, name.as_ptr()) {
+                Ok(())
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    /// Locks an achievement for a player again
+    pub fn clear_user_achievement(&self, user: SteamId, name: &str) -> Result<(), ()> {
+        unsafe {
+            let name = CString::new(name).unwrap();
+            if sys::SteamAPI_ISteamGameServerStats_ClearUserAchievement(self.stats, user.0, name.as_ptr()) {
+                Ok(())
+            } else {
+                Err(())
+            }
+        }
+    }
+}
+
+/// The result of `GameServerStats::request_user_stats`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GSStatsReceived {
+    /// The player whose stats were requested
+    pub steam_id: SteamId,
+    /// Whether the stats were received
+    pub result: SResult<()>,
+}
+
+/// The result of `GameServerStats::store_user_stats`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GSStatsStored {
+    /// The player whose stats were stored
+    pub steam_id: SteamId,
+    /// Whether the stats were stored
+    pub result: SResult<()>,
+}
+
+/// Called when the stats of a player have been unloaded, after
+/// which they must be requested again before they can be used
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GSStatsUnloaded {
+    /// The player whose stats were unloaded
+    pub steam_id: SteamId,
+}
+
+unsafe impl Callback for GSStatsUnloaded {
+    const ID: i32 = 1108;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GSStatsUnloaded_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GSStatsUnloaded_t);
+        GSStatsUnloaded {
+            steam_id: SteamId(val.m_steamIDUser.m_steamid.m_unAll64Bits),
+        }
+    }
+}
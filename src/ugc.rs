@@ -107,6 +107,110 @@ impl Into<sys::EWorkshopFileType> for FileType {
     }
 }
 
+/// The kind of media a workshop item preview contains
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ItemPreviewType {
+    /// A standard image file
+    Image,
+    /// A YouTube video id
+    YouTubeVideo,
+    /// A Sketchfab model id
+    Sketchfab,
+    /// An environment map in the horizontal cross layout
+    EnvironmentMapHorizontalCross,
+    /// An environment map in the lat-long layout
+    EnvironmentMapLatLong,
+}
+
+impl Into<sys::EItemPreviewType> for ItemPreviewType {
+    fn into(self) -> sys::EItemPreviewType {
+        match self {
+            ItemPreviewType::Image => sys::EItemPreviewType::k_EItemPreviewType_Image,
+            ItemPreviewType::YouTubeVideo => sys::EItemPreviewType::k_EItemPreviewType_YouTubeVideo,
+            ItemPreviewType::Sketchfab => sys::EItemPreviewType::k_EItemPreviewType_Sketchfab,
+            ItemPreviewType::EnvironmentMapHorizontalCross => sys::EItemPreviewType::k_EItemPreviewType_EnvironmentMap_HorizontalCross,
+            ItemPreviewType::EnvironmentMapLatLong => sys::EItemPreviewType::k_EItemPreviewType_EnvironmentMap_LatLong,
+        }
+    }
+}
+
+/// Who can see a published workshop item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PublishedFileVisibility {
+    /// Visible to everyone
+    Public,
+    /// Visible to the creator's friends
+    FriendsOnly,
+    /// Only visible to the creator
+    Private,
+    /// Visible to anyone with a link but hidden from searches
+    Unlisted,
+}
+
+impl Into<sys::ERemoteStoragePublishedFileVisibility> for PublishedFileVisibility {
+    fn into(self) -> sys::ERemoteStoragePublishedFileVisibility {
+        match self {
+            PublishedFileVisibility::Public => sys::ERemoteStoragePublishedFileVisibility::k_ERemoteStoragePublishedFileVisibilityPublic,
+            PublishedFileVisibility::FriendsOnly => sys::ERemoteStoragePublishedFileVisibility::k_ERemoteStoragePublishedFileVisibilityFriendsOnly,
+            PublishedFileVisibility::Private => sys::ERemoteStoragePublishedFileVisibility::k_ERemoteStoragePublishedFileVisibilityPrivate,
+            PublishedFileVisibility::Unlisted => sys::ERemoteStoragePublishedFileVisibility::k_ERemoteStoragePublishedFileVisibilityUnlisted,
+        }
+    }
+}
+
+/// The sort order of `query_all`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UGCQueryType {
+    RankedByVote,
+    RankedByPublicationDate,
+    AcceptedForGameRankedByAcceptanceDate,
+    RankedByTrend,
+    FavoritedByFriendsRankedByPublicationDate,
+    CreatedByFriendsRankedByPublicationDate,
+    RankedByNumTimesReported,
+    CreatedByFollowedUsersRankedByPublicationDate,
+    NotYetRated,
+    RankedByTotalVotesAsc,
+    RankedByVotesUp,
+    RankedByTextSearch,
+    RankedByTotalUniqueSubscriptions,
+    RankedByPlaytimeTrend,
+    RankedByTotalPlaytime,
+    RankedByAveragePlaytimeTrend,
+    RankedByLifetimeAveragePlaytime,
+    RankedByPlaytimeSessionsTrend,
+    RankedByLifetimePlaytimeSessions,
+}
+
+impl Into<sys::EUGCQuery> for UGCQueryType {
+    fn into(self) -> sys::EUGCQuery {
+        match self {
+            UGCQueryType::RankedByVote => sys::EUGCQuery::k_EUGCQuery_RankedByVote,
+            UGCQueryType::RankedByPublicationDate => sys::EUGCQuery::k_EUGCQuery_RankedByPublicationDate,
+            UGCQueryType::AcceptedForGameRankedByAcceptanceDate => sys::EUGCQuery::k_EUGCQuery_AcceptedForGameRankedByAcceptanceDate,
+            UGCQueryType::RankedByTrend => sys::EUGCQuery::k_EUGCQuery_RankedByTrend,
+            UGCQueryType::FavoritedByFriendsRankedByPublicationDate => sys::EUGCQuery::k_EUGCQuery_FavoritedByFriendsRankedByPublicationDate,
+            UGCQueryType::CreatedByFriendsRankedByPublicationDate => sys::EUGCQuery::k_EUGCQuery_CreatedByFriendsRankedByPublicationDate,
+            UGCQueryType::RankedByNumTimesReported => sys::EUGCQuery::k_EUGCQuery_RankedByNumTimesReported,
+            UGCQueryType::CreatedByFollowedUsersRankedByPublicationDate => sys::EUGCQuery::k_EUGCQuery_CreatedByFollowedUsersRankedByPublicationDate,
+            UGCQueryType::NotYetRated => sys::EUGCQuery::k_EUGCQuery_NotYetRated,
+            UGCQueryType::RankedByTotalVotesAsc => sys::EUGCQuery::k_EUGCQuery_RankedByTotalVotesAsc,
+            UGCQueryType::RankedByVotesUp => sys::EUGCQuery::k_EUGCQuery_RankedByVotesUp,
+            UGCQueryType::RankedByTextSearch => sys::EUGCQuery::k_EUGCQuery_RankedByTextSearch,
+            UGCQueryType::RankedByTotalUniqueSubscriptions => sys::EUGCQuery::k_EUGCQuery_RankedByTotalUniqueSubscriptions,
+            UGCQueryType::RankedByPlaytimeTrend => sys::EUGCQuery::k_EUGCQuery_RankedByPlaytimeTrend,
+            UGCQueryType::RankedByTotalPlaytime => sys::EUGCQuery::k_EUGCQuery_RankedByTotalPlaytime,
+            UGCQueryType::RankedByAveragePlaytimeTrend => sys::EUGCQuery::k_EUGCQuery_RankedByAveragePlaytimeTrend,
+            UGCQueryType::RankedByLifetimeAveragePlaytime => sys::EUGCQuery::k_EUGCQuery_RankedByLifetimeAveragePlaytime,
+            UGCQueryType::RankedByPlaytimeSessionsTrend => sys::EUGCQuery::k_EUGCQuery_RankedByPlaytimeSessionsTrend,
+            UGCQueryType::RankedByLifetimePlaytimeSessions => sys::EUGCQuery::k_EUGCQuery_RankedByLifetimePlaytimeSessions,
+        }
+    }
+}
+
 /// AppID filter for queries.
 /// The "consumer" app is the app that the content is for.
 /// The "creator" app is a separate editor to create the content in, if applicable.
@@ -202,6 +306,7 @@ impl Into<sys::EUserUGCList> for UserList {
 }
 
 bitflags! {
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub struct ItemState: u32 {
         const NONE = 0;
         const SUBSCRIBED = 1;
@@ -246,6 +351,19 @@ pub struct InstallInfo {
 }
 
 impl <Manager> UGC<Manager> {
+    /// Returns the raw `ISteamUGC` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamUGC {
+        self.ugc
+    }
+
     /// Suspends or resumes all workshop downloads
     pub fn suspend_downloads(&self, suspend: bool) {
         unsafe {
@@ -394,7 +512,7 @@ impl <Manager> UGC<Manager> {
         sort_order: UserListOrder,
         appids: AppIDs,
         page: u32
-    ) -> Result<UserListQuery<Manager>, CreateQueryError> {
+    ) -> Result<QueryHandle<Manager>, CreateQueryError> {
         unsafe {
             let res = sys::SteamAPI_ISteamUGC_CreateQueryUserUGCRequest(
                 self.ugc,
@@ -410,13 +528,121 @@ impl <Manager> UGC<Manager> {
                 return Err(CreateQueryError);
             }
 
-            Ok(UserListQuery {
+            Ok(QueryHandle {
+                ugc: self.ugc,
+                inner: Arc::clone(&self.inner),
+                handle: Some(res),
+            })
+        }
+    }
+
+    /// Queries all workshop items, optionally filtered with
+    /// `search_text` and the other query options.
+    pub fn query_all(&self,
+        query_type: UGCQueryType,
+        item_type: UGCType,
+        appids: AppIDs,
+        page: u32
+    ) -> Result<QueryHandle<Manager>, CreateQueryError> {
+        unsafe {
+            let res = sys::SteamAPI_ISteamUGC_CreateQueryAllUGCRequestPage(
+                self.ugc,
+                query_type.into(),
+                item_type.into(),
+                appids.creator_app_id().unwrap_or(AppId(0)).0,
+                appids.consumer_app_id().unwrap_or(AppId(0)).0,
+                page,
+            );
+            if res == UGCQueryHandleInvalid {
+                return Err(CreateQueryError);
+            }
+
+            Ok(QueryHandle {
+                ugc: self.ugc,
+                inner: Arc::clone(&self.inner),
+                handle: Some(res),
+            })
+        }
+    }
+
+    /// Queries the details of specific workshop items
+    pub fn query_items(&self, items: &[PublishedFileId]) -> Result<QueryHandle<Manager>, CreateQueryError> {
+        unsafe {
+            let mut ids = items.iter().map(|v| v.0).collect::<Vec<_>>();
+            let res = sys::SteamAPI_ISteamUGC_CreateQueryUGCDetailsRequest(
+                self.ugc,
+                ids.as_mut_ptr(),
+                ids.len() as u32,
+            );
+            if res == UGCQueryHandleInvalid {
+                return Err(CreateQueryError);
+            }
+
+            Ok(QueryHandle {
                 ugc: self.ugc,
                 inner: Arc::clone(&self.inner),
                 handle: Some(res),
             })
         }
     }
+
+    /// Runs a paged query until all of the matching results have
+    /// been fetched.
+    ///
+    /// `make_query` is called with the page number, starting at 1, and
+    /// should build the query for that page using `query_all` or
+    /// `query_user` along with any other options. `cb` is called
+    /// with the results of each page in order and can return `false`
+    /// to stop early. Fetching also stops after the first error.
+    pub fn fetch_pages<Q, F>(&self, make_query: Q, cb: F)
+        where Q: FnMut(&UGC<Manager>, u32) -> Result<QueryHandle<Manager>, CreateQueryError> + 'static + Send,
+              F: for<'a> FnMut(u32, Result<QueryResults<'a>, SteamError>) -> bool + 'static + Send,
+              Manager: Send + Sync + 'static,
+    {
+        fetch_page(UGC {
+            ugc: self.ugc,
+            inner: Arc::clone(&self.inner),
+        }, 1, make_query, cb);
+    }
+
+    /// Queries the details of a single workshop item
+    pub fn query_item(&self, item: PublishedFileId) -> Result<QueryHandle<Manager>, CreateQueryError> {
+        self.query_items(&[item])
+    }
+}
+
+fn fetch_page<Manager, Q, F>(ugc: UGC<Manager>, page: u32, mut make_query: Q, mut cb: F)
+    where Q: FnMut(&UGC<Manager>, u32) -> Result<QueryHandle<Manager>, CreateQueryError> + 'static + Send,
+          F: for<'a> FnMut(u32, Result<QueryResults<'a>, SteamError>) -> bool + 'static + Send,
+          Manager: Send + Sync + 'static,
+{
+    let query = match make_query(&ugc, page) {
+        Ok(query) => query,
+        Err(_) => {
+            cb(page, Err(SteamError::Generic));
+            return;
+        }
+    };
+    // Raw pointers aren't `Send` so the interface is carried as an
+    // address for the next page
+    let raw_ugc = ugc.ugc as usize;
+    let inner = Arc::clone(&ugc.inner);
+    let mut state = Some((make_query, cb));
+    query.fetch(move |res| {
+        let (make_query, mut cb) = state.take().expect("query completed twice");
+        let more = match &res {
+            Ok(results) => results.returned_results() > 0
+                && page * sys::kNumUGCResultsPerPage < results.total_results(),
+            Err(_) => false,
+        };
+        if cb(page, res) && more {
+            let ugc = UGC {
+                ugc: raw_ugc as *mut sys::ISteamUGC,
+                inner: Arc::clone(&inner),
+            };
+            fetch_page(ugc, page + 1, make_query, cb);
+        }
+    });
 }
 
 /// A handle to update a published item
@@ -437,6 +663,57 @@ impl <Manager> UpdateHandle<Manager> {
         self
     }
 
+    /// Sets the description of the item
+    #[must_use]
+    pub fn description(self, description: &str) -> Self {
+        unsafe {
+            let description = CString::new(description).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_SetItemDescription(self.ugc, self.handle, description.as_ptr()));
+        }
+        self
+    }
+
+    /// Sets the main preview image of the item.
+    ///
+    /// The file must be under 1MB.
+    #[must_use]
+    pub fn preview_path(self, path: &Path) -> Self {
+        unsafe {
+            let path = path.canonicalize().unwrap();
+            let preview_path = CString::new(&*path.to_string_lossy()).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_SetItemPreview(self.ugc, self.handle, preview_path.as_ptr()));
+        }
+        self
+    }
+
+    /// Replaces the tags of the item
+    #[must_use]
+    pub fn tags<S: AsRef<str>>(self, tags: &[S]) -> Self {
+        unsafe {
+            let tags = tags.iter()
+                .map(|v| CString::new(v.as_ref()).unwrap())
+                .collect::<Vec<_>>();
+            let mut ptrs = tags.iter()
+                .map(|v| v.as_ptr())
+                .collect::<Vec<_>>();
+            let array = sys::SteamParamStringArray_t {
+                m_ppStrings: ptrs.as_mut_ptr(),
+                m_nNumStrings: ptrs.len() as _,
+            };
+            assert!(sys::SteamAPI_ISteamUGC_SetItemTags(self.ugc, self.handle, &array));
+        }
+        self
+    }
+
+    /// Sets who can see the item
+    #[must_use]
+    pub fn visibility(self, visibility: PublishedFileVisibility) -> Self {
+        unsafe {
+            assert!(sys::SteamAPI_ISteamUGC_SetItemVisibility(self.ugc, self.handle, visibility.into()));
+        }
+        self
+    }
+
     #[must_use]
     pub fn content_path(self, path: &Path) -> Self {
         unsafe {
@@ -447,6 +724,100 @@ impl <Manager> UpdateHandle<Manager> {
         self
     }
 
+    /// Adds an additional preview file to the item.
+    ///
+    /// Files must be under 1MB.
+    #[must_use]
+    pub fn add_preview_file(self, path: &Path, preview_type: ItemPreviewType) -> Self {
+        unsafe {
+            let path = path.canonicalize().unwrap();
+            let preview_path = CString::new(&*path.to_string_lossy()).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_AddItemPreviewFile(self.ugc, self.handle, preview_path.as_ptr(), preview_type.into()));
+        }
+        self
+    }
+
+    /// Adds a YouTube video to the item's previews
+    #[must_use]
+    pub fn add_preview_video(self, video_id: &str) -> Self {
+        unsafe {
+            let video_id = CString::new(video_id).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_AddItemPreviewVideo(self.ugc, self.handle, video_id.as_ptr()));
+        }
+        self
+    }
+
+    /// Replaces the additional preview file at the given index
+    #[must_use]
+    pub fn update_preview_file(self, index: u32, path: &Path) -> Self {
+        unsafe {
+            let path = path.canonicalize().unwrap();
+            let preview_path = CString::new(&*path.to_string_lossy()).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_UpdateItemPreviewFile(self.ugc, self.handle, index, preview_path.as_ptr()));
+        }
+        self
+    }
+
+    /// Replaces the YouTube video preview at the given index
+    #[must_use]
+    pub fn update_preview_video(self, index: u32, video_id: &str) -> Self {
+        unsafe {
+            let video_id = CString::new(video_id).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_UpdateItemPreviewVideo(self.ugc, self.handle, index, video_id.as_ptr()));
+        }
+        self
+    }
+
+    /// Removes the additional preview at the given index
+    #[must_use]
+    pub fn remove_preview(self, index: u32) -> Self {
+        unsafe {
+            assert!(sys::SteamAPI_ISteamUGC_RemoveItemPreview(self.ugc, self.handle, index));
+        }
+        self
+    }
+
+    /// Adds a key-value tag to the item.
+    ///
+    /// Keys can map to multiple values. Fails if the key or the value
+    /// is longer than the 255 bytes steam allows.
+    pub fn add_key_value_tag(self, key: &str, value: &str) -> Result<Self, KeyValueTagTooLarge> {
+        check_key_value_tag_len(key)?;
+        check_key_value_tag_len(value)?;
+        unsafe {
+            let key = CString::new(key).unwrap();
+            let value = CString::new(value).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_AddItemKeyValueTag(self.ugc, self.handle, key.as_ptr(), value.as_ptr()));
+        }
+        Ok(self)
+    }
+
+    /// Removes all key-value tags with the given key from the item.
+    ///
+    /// Fails if the key is longer than the 255 bytes steam allows.
+    pub fn remove_key_value_tags(self, key: &str) -> Result<Self, KeyValueTagTooLarge> {
+        check_key_value_tag_len(key)?;
+        unsafe {
+            let key = CString::new(key).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_RemoveItemKeyValueTags(self.ugc, self.handle, key.as_ptr()));
+        }
+        Ok(self)
+    }
+
+    /// Sets the developer metadata of the item.
+    ///
+    /// Fails if the metadata is larger than the 5000 bytes steam allows.
+    pub fn metadata(self, metadata: &str) -> Result<Self, MetadataTooLarge> {
+        if metadata.len() > sys::k_cchDeveloperMetadataMax as usize {
+            return Err(MetadataTooLarge { len: metadata.len() });
+        }
+        unsafe {
+            let metadata = CString::new(metadata).unwrap();
+            assert!(sys::SteamAPI_ISteamUGC_SetItemMetadata(self.ugc, self.handle, metadata.as_ptr()));
+        }
+        Ok(self)
+    }
+
     pub fn submit<F>(self, change_note: Option<&str>, mut cb: F) -> UpdateWatchHandle<Manager>
         where F: FnMut(Result<(PublishedFileId, bool), SteamError>) + 'static + Send
     {
@@ -519,8 +890,9 @@ pub enum UpdateStatus {
     CommittingChanges,
 }
 
-/// Query object from `query_user`, to allow for more filtering.
-pub struct UserListQuery<Manager> {
+/// Query object from `query_user`, `query_all` or `query_items`,
+/// to allow for more filtering.
+pub struct QueryHandle<Manager> {
     ugc: *mut sys::ISteamUGC,
     inner: Arc<Inner<Manager>>,
 
@@ -528,7 +900,10 @@ pub struct UserListQuery<Manager> {
     // to prevent the handle from being dropped when this query is dropped.
     handle: Option<sys::UGCQueryHandle_t>,
 }
-impl <Manager> Drop for UserListQuery<Manager> {
+/// The query object returned by `query_user`
+pub type UserListQuery<Manager> = QueryHandle<Manager>;
+
+impl <Manager> Drop for QueryHandle<Manager> {
     fn drop(&mut self) {
         if let Some(handle) = self.handle.as_mut() {
             unsafe {
@@ -537,7 +912,7 @@ impl <Manager> Drop for UserListQuery<Manager> {
         }
     }
 }
-impl <Manager> UserListQuery<Manager> {
+impl <Manager> QueryHandle<Manager> {
     /// Excludes items with a specific tag.
     ///
     /// Panics if `tag` could not be converted to a `CString`.
@@ -562,6 +937,45 @@ impl <Manager> UserListQuery<Manager> {
         self
     }
 
+    /// Only include items with at least one of the tags in the group.
+    ///
+    /// Each group must match on its own, so calling this for several
+    /// groups requires a match from every group. For example a group
+    /// of `["Maps", "Scenarios"]` matches items tagged with either.
+    ///
+    /// Panics if a tag could not be converted to a `CString`.
+    pub fn require_tag_group<S: AsRef<str>>(self, tags: &[S]) -> Self {
+        let tags = tags.iter()
+            .map(|v| CString::new(v.as_ref()).expect("String passed to require_tag_group could not be converted to a c string"))
+            .collect::<Vec<_>>();
+        let mut ptrs = tags.iter()
+            .map(|v| v.as_ptr())
+            .collect::<Vec<_>>();
+        let array = sys::SteamParamStringArray_t {
+            m_ppStrings: ptrs.as_mut_ptr(),
+            m_nNumStrings: ptrs.len() as _,
+        };
+        let ok = unsafe {
+            sys::SteamAPI_ISteamUGC_AddRequiredTagGroup(self.ugc, self.handle.unwrap(), &array)
+        };
+        debug_assert!(ok);
+        self
+    }
+
+    /// Sets a full text search string to filter the results by.
+    ///
+    /// Only applies to queries created with `query_all`.
+    ///
+    /// Panics if `text` could not be converted to a `CString`.
+    pub fn search_text(self, text: &str) -> Self {
+        let cstr = CString::new(text).expect("String passed to search_text could not be converted to a c string");
+        let ok = unsafe {
+            sys::SteamAPI_ISteamUGC_SetSearchText(self.ugc, self.handle.unwrap(), cstr.as_ptr())
+        };
+        debug_assert!(ok);
+        self
+    }
+
     /// Sets how to match tags added by `require_tag`. If `true`, then any tag may match. If `false`, all required tags must match.
     pub fn any_required(self, any: bool) -> Self {
         let ok = unsafe {
@@ -594,6 +1008,29 @@ impl <Manager> UserListQuery<Manager> {
         self
     }
 
+    /// Only return the IDs of the items in results.
+    ///
+    /// This is cheaper than a full query. `fetch_ids` sets this
+    /// automatically.
+    pub fn only_ids(self, only_ids: bool) -> Self {
+        let ok = unsafe {
+            sys::SteamAPI_ISteamUGC_SetReturnOnlyIDs(self.ugc, self.handle.unwrap(), only_ids)
+        };
+        debug_assert!(ok);
+        self
+    }
+
+    /// Only return the total number of matching items.
+    ///
+    /// `fetch_total` sets this automatically.
+    pub fn only_total(self, only_total: bool) -> Self {
+        let ok = unsafe {
+            sys::SteamAPI_ISteamUGC_SetReturnTotalOnly(self.ugc, self.handle.unwrap(), only_total)
+        };
+        debug_assert!(ok);
+        self
+    }
+
     /// Include the full description in results
     pub fn include_long_desc(self, include: bool) -> Self {
         let ok = unsafe {
@@ -621,6 +1058,28 @@ impl <Manager> UserListQuery<Manager> {
         self
     }
 
+    /// Include key-value tags in results
+    pub fn include_key_value_tags(self, include: bool) -> Self {
+        let ok = unsafe {
+            sys::SteamAPI_ISteamUGC_SetReturnKeyValueTags(self.ugc, self.handle.unwrap(), include)
+        };
+        debug_assert!(ok);
+        self
+    }
+
+    /// Only include items with the given key-value tag.
+    ///
+    /// Panics if `key` or `value` could not be converted to a `CString`.
+    pub fn require_key_value_tag(self, key: &str, value: &str) -> Self {
+        let key = CString::new(key).expect("String passed to require_key_value_tag could not be converted to a c string");
+        let value = CString::new(value).expect("String passed to require_key_value_tag could not be converted to a c string");
+        let ok = unsafe {
+            sys::SteamAPI_ISteamUGC_AddRequiredKeyValueTag(self.ugc, self.handle.unwrap(), key.as_ptr(), value.as_ptr())
+        };
+        debug_assert!(ok);
+        self
+    }
+
     /// Include additional previews in results
     pub fn include_additional_previews(self, include: bool) -> Self {
         let ok = unsafe {
@@ -776,6 +1235,50 @@ impl<'a> QueryResults<'a> {
         }
     }
 
+    /// Gets the developer metadata of a result.
+    ///
+    /// Requires `include_metadata` to have been set on the query.
+    pub fn metadata(&self, index: u32) -> Option<String> {
+        if index >= self.num_results_returned {
+            return None;
+        }
+        unsafe {
+            let mut buf = vec![0 as libc::c_char; sys::k_cchDeveloperMetadataMax as usize + 1];
+            if !sys::SteamAPI_ISteamUGC_GetQueryUGCMetadata(self.ugc, self.handle, index, buf.as_mut_ptr(), buf.len() as u32) {
+                return None;
+            }
+            Some(CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned())
+        }
+    }
+
+    /// Gets the key-value tags of a result.
+    ///
+    /// Requires `include_key_value_tags` to have been set on the query.
+    pub fn key_value_tags(&self, index: u32) -> Vec<(String, String)> {
+        if index >= self.num_results_returned {
+            return Vec::new();
+        }
+        unsafe {
+            let count = sys::SteamAPI_ISteamUGC_GetQueryUGCNumKeyValueTags(self.ugc, self.handle, index);
+            let mut tags = Vec::with_capacity(count as usize);
+            let mut key = [0 as libc::c_char; 256];
+            let mut value = [0 as libc::c_char; 256];
+            for tag in 0 .. count {
+                if sys::SteamAPI_ISteamUGC_GetQueryUGCKeyValueTag(
+                    self.ugc, self.handle, index, tag,
+                    key.as_mut_ptr(), key.len() as u32,
+                    value.as_mut_ptr(), value.len() as u32,
+                ) {
+                    tags.push((
+                        CStr::from_ptr(key.as_ptr()).to_string_lossy().into_owned(),
+                        CStr::from_ptr(value.as_ptr()).to_string_lossy().into_owned(),
+                    ));
+                }
+            }
+            tags
+        }
+    }
+
     /// Returns an iterator that runs over all the fetched results
     pub fn iter<'b>(&'b self) -> impl Iterator<Item=QueryResult> + 'b {
         (0..self.returned_results())
@@ -819,3 +1322,41 @@ impl fmt::Display for CreateQueryError {
     }
 }
 impl error::Error for CreateQueryError {}
+
+/// Returned when item metadata is larger than steam allows
+#[derive(Debug,Clone,Copy)]
+pub struct MetadataTooLarge {
+    /// The length of the rejected metadata in bytes
+    pub len: usize,
+}
+impl fmt::Display for MetadataTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Item metadata is {} bytes but at most {} are allowed", self.len, sys::k_cchDeveloperMetadataMax)
+    }
+}
+impl error::Error for MetadataTooLarge {}
+
+/// The longest key or value of a key-value tag steam allows, in bytes
+const MAX_KEY_VALUE_TAG_LEN: usize = 255;
+
+fn check_key_value_tag_len(s: &str) -> Result<(), KeyValueTagTooLarge> {
+    if s.len() > MAX_KEY_VALUE_TAG_LEN {
+        Err(KeyValueTagTooLarge { len: s.len() })
+    } else {
+        Ok(())
+    }
+}
+
+/// Returned when the key or value of a key-value tag is longer than
+/// steam allows
+#[derive(Debug,Clone,Copy)]
+pub struct KeyValueTagTooLarge {
+    /// The length of the rejected key or value in bytes
+    pub len: usize,
+}
+impl fmt::Display for KeyValueTagTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Key-value tag is {} bytes but at most {} are allowed", self.len, MAX_KEY_VALUE_TAG_LEN)
+    }
+}
+impl error::Error for KeyValueTagTooLarge {}
@@ -9,6 +9,19 @@ pub struct User<Manager> {
 }
 
 impl <Manager> User<Manager> {
+    /// Returns the raw `ISteamUser` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamUser {
+        self.user
+    }
+
     /// Returns the steam id of the current user
     pub fn steam_id(&self) -> SteamId {
         unsafe {
@@ -25,15 +38,26 @@ impl <Manager> User<Manager> {
     /// for the `AuthSessionTicketResponse` event before trying to
     /// use the ticket.
     ///
-    /// When the multiplayer session terminates you must call
-    /// `cancel_authentication_ticket`
-    pub fn authentication_session_ticket(&self) -> (AuthTicket, Vec<u8>) {
+    /// The ticket is cancelled when the returned `AuthTicket` is
+    /// dropped, which should happen once the multiplayer session
+    /// terminates.
+    ///
+    /// Fails with `SteamError::Generic` if steam couldn't create
+    /// a ticket.
+    pub fn authentication_session_ticket(&self) -> SResult<(AuthTicket<Manager>, Vec<u8>)> {
         unsafe {
             let mut ticket = vec![0; 1024];
             let mut ticket_len = 0;
             let auth_ticket = sys::SteamAPI_ISteamUser_GetAuthSessionTicket(self.user, ticket.as_mut_ptr() as *mut _, 1024, &mut ticket_len);
+            if auth_ticket == sys::k_HAuthTicketInvalid {
+                return Err(SteamError::Generic);
+            }
             ticket.truncate(ticket_len as usize);
-            (AuthTicket(auth_ticket), ticket)
+            Ok((AuthTicket {
+                handle: AuthTicketHandle(auth_ticket),
+                source: TicketSource::User(self.user),
+                _inner: self._inner.clone(),
+            }, ticket))
         }
     }
 
@@ -41,11 +65,9 @@ impl <Manager> User<Manager> {
     /// `authentication_session_ticket`.
     ///
     /// This should be called when you are no longer playing with
-    /// the specified entity.
-    pub fn cancel_authentication_ticket(&self, ticket: AuthTicket) {
-        unsafe {
-            sys::SteamAPI_ISteamUser_CancelAuthTicket(self.user, ticket.0);
-        }
+    /// the specified entity. It is the same as dropping the ticket.
+    pub fn cancel_authentication_ticket(&self, ticket: AuthTicket<Manager>) {
+        drop(ticket);
     }
 
     /// Authenticate the ticket from the steam ID to make sure it is
@@ -85,6 +107,176 @@ impl <Manager> User<Manager> {
             sys::SteamAPI_ISteamUser_EndAuthSession(self.user, user.0);
         }
     }
+
+    /// Checks whether a user with an authentication session owns
+    /// a license for the app, e.g. a dlc.
+    ///
+    /// This allows a listen server to check the entitlements of
+    /// remote players. Returns `UserHasLicense::NoAuth` if the user's
+    /// session hasn't been started with `begin_authentication_session`.
+    pub fn user_has_license_for_app(&self, user: SteamId, app_id: AppId) -> UserHasLicense {
+        unsafe {
+            sys::SteamAPI_ISteamUser_UserHasLicenseForApp(self.user, user.0, app_id.0).into()
+        }
+    }
+
+    /// Starts recording voice from the user's microphone.
+    ///
+    /// The recorded voice can be read with `voice`.
+    pub fn start_voice_recording(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamUser_StartVoiceRecording(self.user);
+        }
+    }
+
+    /// Stops recording voice.
+    ///
+    /// Recording continues for a short while afterwards so the
+    /// end of the speech isn't cut off. `voice` should be called
+    /// until it returns `VoiceError::NotRecording`.
+    pub fn stop_voice_recording(&self) {
+        unsafe {
+            sys::SteamAPI_ISteamUser_StopVoiceRecording(self.user);
+        }
+    }
+
+    /// Returns the number of bytes of compressed voice data that
+    /// are waiting to be read with `voice`.
+    pub fn available_voice(&self) -> Result<u32, VoiceError> {
+        unsafe {
+            let mut compressed = 0;
+            let res = sys::SteamAPI_ISteamUser_GetAvailableVoice(self.user, &mut compressed, std::ptr::null_mut(), 0);
+            voice_result(res).map(|_| compressed)
+        }
+    }
+
+    /// Reads the recorded voice into `buf` in steam's compressed
+    /// format, replacing its contents.
+    ///
+    /// The data should be sent to other players and played back
+    /// with `decompress_voice`.
+    pub fn voice(&self, buf: &mut Vec<u8>) -> Result<(), VoiceError> {
+        let available = self.available_voice()?;
+        buf.clear();
+        buf.resize(available.max(1024) as usize, 0);
+        unsafe {
+            let mut written = 0;
+            let res = sys::SteamAPI_ISteamUser_GetVoice(
+                self.user, true,
+                buf.as_mut_ptr() as *mut _, buf.len() as _, &mut written,
+                false, std::ptr::null_mut(), 0, std::ptr::null_mut(), 0,
+            );
+            buf.truncate(written as usize);
+            voice_result(res)
+        }
+    }
+
+    /// Decompresses voice data received from `voice` into signed
+    /// 16 bit mono samples at the given sample rate, replacing the
+    /// contents of `samples`.
+    pub fn decompress_voice(&self, compressed: &[u8], sample_rate: u32, samples: &mut Vec<i16>) -> Result<(), VoiceError> {
+        samples.clear();
+        samples.resize(sample_rate as usize / 4, 0);
+        loop {
+            unsafe {
+                let mut written = 0;
+                let res = sys::SteamAPI_ISteamUser_DecompressVoice(
+                    self.user,
+                    compressed.as_ptr() as *const _, compressed.len() as _,
+                    samples.as_mut_ptr() as *mut _, (samples.len() * 2) as _, &mut written,
+                    sample_rate,
+                );
+                match voice_result(res) {
+                    Err(VoiceError::BufferTooSmall) => {
+                        let len = (written as usize / 2).max(samples.len() * 2);
+                        samples.resize(len, 0);
+                    },
+                    res => {
+                        samples.truncate(written as usize / 2);
+                        return res;
+                    },
+                }
+            }
+        }
+    }
+
+    /// Returns the sample rate that decompresses voice with the
+    /// best quality
+    pub fn voice_optimal_sample_rate(&self) -> u32 {
+        unsafe {
+            sys::SteamAPI_ISteamUser_GetVoiceOptimalSampleRate(self.user)
+        }
+    }
+}
+
+fn voice_result(res: sys::EVoiceResult) -> Result<(), VoiceError> {
+    Err(match res {
+        sys::EVoiceResult::k_EVoiceResultOK => return Ok(()),
+        sys::EVoiceResult::k_EVoiceResultNotInitialized => VoiceError::NotInitialized,
+        sys::EVoiceResult::k_EVoiceResultNotRecording => VoiceError::NotRecording,
+        sys::EVoiceResult::k_EVoiceResultNoData => VoiceError::NoData,
+        sys::EVoiceResult::k_EVoiceResultBufferTooSmall => VoiceError::BufferTooSmall,
+        sys::EVoiceResult::k_EVoiceResultDataCorrupted => VoiceError::DataCorrupted,
+        sys::EVoiceResult::k_EVoiceResultRestricted => VoiceError::Restricted,
+        sys::EVoiceResult::k_EVoiceResultUnsupportedCodec => VoiceError::UnsupportedCodec,
+        sys::EVoiceResult::k_EVoiceResultReceiverOutOfDate => VoiceError::ReceiverOutOfDate,
+        _ => VoiceError::ReceiverDidNotAnswer,
+    })
+}
+
+/// Errors from the voice functions of `User`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Fail)]
+pub enum VoiceError {
+    /// The voice system isn't initialized
+    #[fail(display = "voice not initialized")]
+    NotInitialized,
+    /// Voice isn't being recorded
+    #[fail(display = "not recording")]
+    NotRecording,
+    /// There is no voice data available
+    #[fail(display = "no voice data")]
+    NoData,
+    /// The buffer was too small for the voice data
+    #[fail(display = "buffer too small")]
+    BufferTooSmall,
+    /// The voice data is corrupted
+    #[fail(display = "voice data corrupted")]
+    DataCorrupted,
+    /// The user is restricted from using voice chat
+    #[fail(display = "voice chat restricted")]
+    Restricted,
+    /// The voice data uses an unsupported codec
+    #[fail(display = "unsupported codec")]
+    UnsupportedCodec,
+    /// The receiver's steam client is too old
+    #[fail(display = "receiver out of date")]
+    ReceiverOutOfDate,
+    /// The receiver didn't answer
+    #[fail(display = "receiver did not answer")]
+    ReceiverDidNotAnswer,
+}
+
+/// Whether a user owns a license for an app
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UserHasLicense {
+    /// The user has a license for the app
+    HasLicense,
+    /// The user doesn't have a license for the app
+    DoesNotHaveLicense,
+    /// The user doesn't have an authentication session, so
+    /// their licenses aren't known
+    NoAuth,
+}
+
+impl From<sys::EUserHasLicenseForAppResult> for UserHasLicense {
+    fn from(res: sys::EUserHasLicenseForAppResult) -> Self {
+        match res {
+            sys::EUserHasLicenseForAppResult::k_EUserHasLicenseResultHasLicense => UserHasLicense::HasLicense,
+            sys::EUserHasLicenseForAppResult::k_EUserHasLicenseResultDoesNotHaveLicense => UserHasLicense::DoesNotHaveLicense,
+            _ => UserHasLicense::NoAuth,
+        }
+    }
 }
 
 /// Errors from `begin_authentication_session`
@@ -117,7 +309,7 @@ fn test() {
     let _cb = client.register_callback(|v: ValidateAuthTicketResponse| println!("{:?}", v));
 
     let id = user.steam_id();
-    let (auth, ticket) = user.authentication_session_ticket();
+    let (auth, ticket) = user.authentication_session_ticket().unwrap();
 
     println!("{:?}", user.begin_authentication_session(id, &ticket));
 
@@ -138,16 +330,67 @@ fn test() {
     user.end_authentication_session(id);
 }
 
-/// A handle for an authentication ticket that can be used to cancel
-/// it.
-pub struct AuthTicket(pub(crate) sys::HAuthTicket);
+/// Identifies an authentication ticket, e.g. in an
+/// `AuthSessionTicketResponse`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuthTicketHandle(pub(crate) sys::HAuthTicket);
+
+impl AuthTicketHandle {
+    /// Returns the raw 32 bit value of the ticket handle
+    pub fn raw(&self) -> u32 {
+        self.0
+    }
+}
+
+pub(crate) enum TicketSource {
+    User(*mut sys::ISteamUser),
+    Server(*mut sys::ISteamGameServer),
+}
+
+/// An authentication ticket created by `authentication_session_ticket`
+///
+/// The ticket is cancelled when this is dropped. Tickets are
+/// deliberately not `Clone` so that each ticket is only cancelled
+/// once.
+pub struct AuthTicket<Manager> {
+    pub(crate) handle: AuthTicketHandle,
+    pub(crate) source: TicketSource,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+unsafe impl <Manager: Send + Sync> Send for AuthTicket<Manager> {}
+
+impl <Manager> AuthTicket<Manager> {
+    /// Returns the handle identifying the ticket
+    pub fn handle(&self) -> AuthTicketHandle {
+        self.handle
+    }
+}
+
+impl <Manager> fmt::Debug for AuthTicket<Manager> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("AuthTicket").field(&self.handle).finish()
+    }
+}
+
+impl <Manager> Drop for AuthTicket<Manager> {
+    fn drop(&mut self) {
+        unsafe {
+            match self.source {
+                TicketSource::User(user) => sys::SteamAPI_ISteamUser_CancelAuthTicket(user, self.handle.0),
+                TicketSource::Server(server) => sys::SteamAPI_ISteamGameServer_CancelAuthTicket(server, self.handle.0),
+            }
+        }
+    }
+}
 
 /// Called when generating a authentication session ticket.
 ///
 /// This can be used to verify the ticket was created successfully.
+#[derive(Debug)]
 pub struct AuthSessionTicketResponse {
     /// The ticket in question
-    pub ticket: AuthTicket,
+    pub ticket: AuthTicketHandle,
     /// The result of generating the ticket
     pub result: SResult<()>,
 }
@@ -159,7 +402,7 @@ unsafe impl Callback for AuthSessionTicketResponse {
     unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
         let val = &mut *(raw as *mut sys::GetAuthSessionTicketResponse_t);
         AuthSessionTicketResponse {
-            ticket: AuthTicket(val.m_hAuthTicket),
+            ticket: AuthTicketHandle(val.m_hAuthTicket),
             result: if val.m_eResult == sys::EResult::k_EResultOK  {
                 Ok(())
             } else {
@@ -209,6 +452,68 @@ unsafe impl Callback for ValidateAuthTicketResponse {
     }
 }
 
+/// Called when a connection to the steam servers has been
+/// established.
+///
+/// This is also called for game servers once they have logged on.
+#[derive(Clone, Debug)]
+pub struct SteamServersConnected;
+
+unsafe impl Callback for SteamServersConnected {
+    const ID: i32 = 101;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamServersConnected_t>() as i32;
+
+    unsafe fn from_raw(_: *mut libc::c_void) -> Self {
+        SteamServersConnected
+    }
+}
+
+/// Called when an attempt to connect to the steam servers
+/// failed.
+#[derive(Debug)]
+pub struct SteamServerConnectFailure {
+    /// The reason the connection failed
+    pub reason: SteamError,
+    /// Whether steam is still trying to connect
+    pub still_retrying: bool,
+}
+
+unsafe impl Callback for SteamServerConnectFailure {
+    const ID: i32 = 102;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamServerConnectFailure_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamServerConnectFailure_t);
+        SteamServerConnectFailure {
+            reason: val.m_eResult.into(),
+            still_retrying: val.m_bStillRetrying,
+        }
+    }
+}
+
+/// Called when the connection to the steam servers has been
+/// lost.
+///
+/// Steam will keep trying to reconnect and will send
+/// `SteamServersConnected` once it does.
+#[derive(Debug)]
+pub struct SteamServersDisconnected {
+    /// The reason the connection was lost
+    pub reason: SteamError,
+}
+
+unsafe impl Callback for SteamServersDisconnected {
+    const ID: i32 = 103;
+    const SIZE: i32 = ::std::mem::size_of::<sys::SteamServersDisconnected_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::SteamServersDisconnected_t);
+        SteamServersDisconnected {
+            reason: val.m_eResult.into(),
+        }
+    }
+}
+
 /// Errors from `ValidateAuthTicketResponse`
 #[derive(Debug, Fail)]
 pub enum AuthSessionValidateError {
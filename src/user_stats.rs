@@ -1,7 +1,9 @@
 pub mod stats;
 mod stat_callback;
+mod cache;
 
 pub use self::stat_callback::*;
+pub use self::cache::*;
 use super::*;
 #[cfg(test)]
 use serial_test_derive::serial;
@@ -15,6 +17,19 @@ pub struct UserStats<Manager> {
 const CALLBACK_BASE_ID: i32 = 1100;
 
 impl <Manager> UserStats<Manager> {
+    /// Returns the raw `ISteamUserStats` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamUserStats {
+        self.user_stats
+    }
+
 
     pub fn find_leaderboard<F>(&self, name: &str, mut cb: F)
         where F: FnMut(Result<Option<Leaderboard>, SteamError>) + 'static + Send
@@ -90,6 +105,7 @@ impl <Manager> UserStats<Manager> {
                     } else {
                         Ok(if v.m_bSuccess != 0 {
                             Some(LeaderboardScoreUploaded {
+                                leaderboard: Leaderboard(v.m_hSteamLeaderboard),
                                 score: v.m_nScore,
                                 was_changed: v.m_bScoreChanged != 0,
                                 global_rank_new: v.m_nGlobalRankNew as _,
@@ -111,6 +127,23 @@ impl <Manager> UserStats<Manager> {
         mut cb: F
     )
         where F: FnMut(Result<Vec<LeaderboardEntry>, SteamError>) + 'static + Send
+    {
+        self.download_leaderboard_scores(
+            leaderboard, request, start, end, max_details_len,
+            move |res| cb(res.map(|v| v.entries)),
+        );
+    }
+
+    /// Downloads entries from a leaderboard like `download_leaderboard_entries`
+    /// but also returns which leaderboard they belong to.
+    pub fn download_leaderboard_scores<F>(
+        &self,
+        leaderboard: &Leaderboard,
+        request: LeaderboardDataRequest, start: usize, end: usize,
+        max_details_len: usize,
+        mut cb: F
+    )
+        where F: FnMut(Result<LeaderboardScoresDownloaded, SteamError>) + 'static + Send
     {
         unsafe {
             let request = match request {
@@ -142,7 +175,10 @@ impl <Manager> UserStats<Manager> {
                                 details,
                             })
                         }
-                        Ok(entries)
+                        Ok(LeaderboardScoresDownloaded {
+                            leaderboard: Leaderboard(v.m_hSteamLeaderboard),
+                            entries,
+                        })
                     })
             });
         }
@@ -175,6 +211,16 @@ impl <Manager> UserStats<Manager> {
     pub fn achievement(&self, name: &str) -> stats::AchievementHelper<'_, Manager> {
         stats::AchievementHelper { name: CString::new(name).unwrap(), parent: self }
     }
+
+    /// Access stat API for a given stat 'API Name'.
+    ///
+    /// Requires [`request_current_stats()`](#method.request_current_stats) to have been called
+    /// and a successful [`UserStatsReceived`](./struct.UserStatsReceived.html) callback processed.
+    #[inline]
+    #[must_use]
+    pub fn stat(&self, name: &str) -> stats::StatHelper<'_, Manager> {
+        stats::StatHelper { name: CString::new(name).unwrap(), parent: self }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -192,15 +238,27 @@ pub enum LeaderboardDataRequest {
     Friends,
 }
 
-#[derive(Clone, Debug)]
+/// The result of a successful `upload_leaderboard_score`
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LeaderboardScoreUploaded {
+    /// The leaderboard the score was uploaded to
+    pub leaderboard: Leaderboard,
     pub score: i32,
     pub was_changed: bool,
     pub global_rank_new: i32,
     pub global_rank_previous: i32,
 }
 
+/// The result of `download_leaderboard_scores`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LeaderboardScoresDownloaded {
+    /// The leaderboard the entries were downloaded from
+    pub leaderboard: Leaderboard,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum UploadScoreMethod {
@@ -223,10 +281,19 @@ pub enum LeaderboardDisplayType {
     TimeMilliSeconds,
 }
 
-#[derive(Clone, Debug)]
+/// A handle to a leaderboard returned by `find_leaderboard` or
+/// `find_or_create_leaderboard`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Leaderboard(u64);
 
+impl Leaderboard {
+    /// Returns the raw 64 bit value of the leaderboard handle
+    pub fn raw(&self) -> u64 {
+        self.0
+    }
+}
+
 #[test]
 #[serial]
 fn test() {
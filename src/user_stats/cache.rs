@@ -0,0 +1,292 @@
+use super::*;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// A pending write to a stat
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PendingStat {
+    Int(i32),
+    Float(f32),
+}
+
+/// Stat and achievement writes. Stats and achievements are kept
+/// apart as a stat and an achievement may share a name.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Writes {
+    stats: BTreeMap<String, PendingStat>,
+    achievements: BTreeSet<String>,
+}
+
+impl Writes {
+    fn is_empty(&self) -> bool {
+        self.stats.is_empty() && self.achievements.is_empty()
+    }
+
+    /// Adds the older writes without replacing any newer ones
+    fn merge_older(&mut self, older: Writes) {
+        for (name, value) in older.stats {
+            self.stats.entry(name).or_insert(value);
+        }
+        self.achievements.extend(older.achievements);
+    }
+}
+
+/// Batches stat and achievement writes and stores them at a fixed
+/// interval.
+///
+/// Calling `store_stats` after every change gets rate limited by
+/// steam. Writes made through the cache are only remembered until
+/// `tick` decides it is time to store, at which point they are all
+/// applied and stored together. Stored writes are kept until a
+/// `UserStatsStored` callback reports that steam saved them, and are
+/// queued again if it reports a failure.
+///
+/// With `offline_cache` set, writes that couldn't be stored (e.g.
+/// because steam is offline and the stats haven't been received)
+/// are saved to the given file and replayed by a later `tick`, even
+/// from the next run of the game.
+///
+/// ```no_run
+/// # use steamworks::*;
+/// # use std::time::Duration;
+/// # let (client, single) = Client::init().unwrap();
+/// let mut stats = StatsCache::new(client.user_stats(), Duration::from_secs(60));
+/// stats.set_i32("KILLS", 10);
+/// loop {
+///     single.run_callbacks();
+///     stats.tick();
+/// #   break;
+/// }
+/// ```
+pub struct StatsCache<Manager> {
+    user_stats: UserStats<Manager>,
+    pending: Writes,
+    storing: Option<Writes>,
+    stored: Arc<Mutex<Option<SResult<()>>>>,
+    interval: Duration,
+    last_store: Option<Instant>,
+    cache_path: Option<PathBuf>,
+    _callback: CallbackHandle<Manager>,
+}
+
+impl <Manager> StatsCache<Manager> {
+    /// Creates a cache that stores at most once per `interval`
+    pub fn new(user_stats: UserStats<Manager>, interval: Duration) -> StatsCache<Manager> {
+        let stored = Arc::new(Mutex::new(None));
+        let slot = Arc::clone(&stored);
+        let callback = unsafe {
+            register_callback(&user_stats.inner, move |v: UserStatsStored| {
+                *slot.lock().unwrap() = Some(v.result);
+            }, false)
+        };
+        StatsCache {
+            user_stats,
+            pending: Writes::default(),
+            storing: None,
+            stored,
+            interval,
+            last_store: None,
+            cache_path: None,
+            _callback: callback,
+        }
+    }
+
+    /// Persists writes that couldn't be stored to the given file.
+    ///
+    /// Writes left over from a previous run are loaded from the file
+    /// straight away.
+    pub fn offline_cache(mut self, path: PathBuf) -> io::Result<Self> {
+        match fs::read_to_string(&path) {
+            Ok(data) => self.pending.merge_older(parse_pending(&data)),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => {},
+            Err(err) => return Err(err),
+        }
+        self.cache_path = Some(path);
+        Ok(self)
+    }
+
+    /// Sets the value of an integer stat
+    pub fn set_i32(&mut self, name: &str, value: i32) {
+        self.pending.stats.insert(name.to_owned(), PendingStat::Int(value));
+    }
+
+    /// Sets the value of a float stat
+    pub fn set_f32(&mut self, name: &str, value: f32) {
+        self.pending.stats.insert(name.to_owned(), PendingStat::Float(value));
+    }
+
+    /// Unlocks an achievement
+    pub fn unlock_achievement(&mut self, name: &str) {
+        self.pending.achievements.insert(name.to_owned());
+    }
+
+    /// Returns the stat writes that haven't been stored yet
+    pub fn pending_stats(&self) -> &BTreeMap<String, PendingStat> {
+        &self.pending.stats
+    }
+
+    /// Returns the achievements that haven't been stored yet
+    pub fn pending_achievements(&self) -> &BTreeSet<String> {
+        &self.pending.achievements
+    }
+
+    /// Returns whether a store is waiting for steam to confirm it
+    pub fn is_storing(&self) -> bool {
+        self.storing.is_some()
+    }
+
+    /// Handles the result of the last store and stores the pending
+    /// writes if the interval has passed since the last store.
+    ///
+    /// This should be called regularly, e.g. once per a frame.
+    pub fn tick(&mut self) {
+        let result = self.stored.lock().unwrap().take();
+        if let Some(result) = result {
+            if let Some(storing) = self.storing.take() {
+                if result.is_err() {
+                    self.pending.merge_older(storing);
+                }
+            }
+            self.save_offline();
+        }
+
+        let due = self.last_store
+            .map_or(true, |last| last.elapsed() >= self.interval);
+        if due && self.storing.is_none() && !self.pending.is_empty() {
+            self.flush();
+        }
+    }
+
+    /// Applies and stores all pending writes immediately.
+    ///
+    /// Returns whether steam accepted the store of every write. The
+    /// writes are still kept until a `UserStatsStored` callback
+    /// confirms them, and writes that couldn't be applied or stored
+    /// are retried by a later `tick` and saved to the offline cache
+    /// if one is set.
+    pub fn flush(&mut self) -> bool {
+        self.last_store = Some(Instant::now());
+
+        let user_stats = &self.user_stats;
+        let mut applied = Writes::default();
+        for (name, value) in &self.pending.stats {
+            let res = match *value {
+                PendingStat::Int(v) => user_stats.stat(name).set_i32(v),
+                PendingStat::Float(v) => user_stats.stat(name).set_f32(v),
+            };
+            if res.is_ok() {
+                applied.stats.insert(name.clone(), *value);
+            }
+        }
+        for name in &self.pending.achievements {
+            if user_stats.achievement(name).set().is_ok() {
+                applied.achievements.insert(name.clone());
+            }
+        }
+        let stored = user_stats.store_stats().is_ok();
+        if stored {
+            for name in applied.stats.keys() {
+                self.pending.stats.remove(name);
+            }
+            for name in &applied.achievements {
+                self.pending.achievements.remove(name);
+            }
+            if let Some(storing) = self.storing.take() {
+                applied.merge_older(storing);
+            }
+            self.storing = Some(applied);
+        }
+
+        self.save_offline();
+        stored && self.pending.is_empty()
+    }
+
+    /// Saves the writes steam hasn't confirmed yet to the offline
+    /// cache
+    fn save_offline(&self) {
+        let path = match &self.cache_path {
+            Some(path) => path,
+            None => return,
+        };
+        let mut writes = self.pending.clone();
+        if let Some(storing) = &self.storing {
+            writes.merge_older(storing.clone());
+        }
+        // Failing to write the cache only loses the offline copy,
+        // the writes are still retried while the game is running.
+        let _ = if writes.is_empty() {
+            fs::remove_file(path).or_else(|err| match err.kind() {
+                io::ErrorKind::NotFound => Ok(()),
+                _ => Err(err),
+            })
+        } else {
+            fs::write(path, format_pending(&writes))
+        };
+    }
+}
+
+impl <Manager> Drop for StatsCache<Manager> {
+    fn drop(&mut self) {
+        if !self.pending.is_empty() {
+            self.flush();
+        }
+    }
+}
+
+fn format_pending(writes: &Writes) -> String {
+    let mut out = String::new();
+    for (name, value) in &writes.stats {
+        match *value {
+            PendingStat::Int(v) => out.push_str(&format!("i {} {}\n", name, v)),
+            PendingStat::Float(v) => out.push_str(&format!("f {} {}\n", name, v)),
+        }
+    }
+    for name in &writes.achievements {
+        out.push_str(&format!("a {}\n", name));
+    }
+    out
+}
+
+fn parse_pending(data: &str) -> Writes {
+    let mut writes = Writes::default();
+    for line in data.lines() {
+        let mut parts = line.split_whitespace();
+        let (kind, name) = match (parts.next(), parts.next()) {
+            (Some(kind), Some(name)) => (kind, name.to_owned()),
+            _ => continue,
+        };
+        let value = parts.next().unwrap_or("");
+        match kind {
+            "i" => if let Ok(v) = value.parse() {
+                writes.stats.insert(name, PendingStat::Int(v));
+            },
+            "f" => if let Ok(v) = value.parse() {
+                writes.stats.insert(name, PendingStat::Float(v));
+            },
+            "a" => {
+                writes.achievements.insert(name);
+            },
+            _ => {},
+        }
+    }
+    writes
+}
+
+#[test]
+fn pending_roundtrip() {
+    let mut writes = Writes::default();
+    writes.stats.insert("KILLS".to_owned(), PendingStat::Int(-3));
+    writes.stats.insert("DISTANCE".to_owned(), PendingStat::Float(12.5));
+    writes.achievements.insert("WIN_THE_GAME".to_owned());
+    // A stat and an achievement with the same name are kept apart
+    writes.achievements.insert("KILLS".to_owned());
+
+    let data = format_pending(&writes);
+    assert_eq!(parse_pending(&data), writes);
+
+    assert!(parse_pending("x BAD 1\ni MISSING\ni NOT_A_NUMBER abc\n").is_empty());
+}
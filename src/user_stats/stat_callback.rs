@@ -73,6 +73,27 @@ unsafe impl Callback for UserStatsStored {
     }
 }
 
+/// Callback triggered when the stats of a user other than the
+/// current one have been unloaded.
+///
+/// The stats have to be requested again before they can be read.
+#[derive(Debug)]
+pub struct UserStatsUnloaded {
+    pub steam_id: SteamId,
+}
+
+unsafe impl Callback for UserStatsUnloaded {
+    const ID: i32 = CALLBACK_BASE_ID + 8;
+    const SIZE: i32 = std::mem::size_of::<sys::UserStatsUnloaded_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::UserStatsUnloaded_t);
+        Self {
+            steam_id: SteamId(val.m_steamIDUser.m_steamid.m_unAll64Bits),
+        }
+    }
+}
+
 /// Result of a request to store the achievements on the server, or an "indicate progress" call.
 /// If both `current_progress` and `max_progress` are zero, that means the achievement has been
 /// fully unlocked.
@@ -96,16 +117,24 @@ pub struct UserAchievementStored {
     pub max_progress: u32,
 }
 
+impl UserAchievementStored {
+    /// Returns whether this was sent because the achievement was
+    /// unlocked rather than for a progress update
+    pub fn is_unlocked(&self) -> bool {
+        self.current_progress == 0 && self.max_progress == 0
+    }
+}
+
 unsafe impl Callback for UserAchievementStored {
     const ID: i32 = CALLBACK_BASE_ID + 3;
     const SIZE: i32 = std::mem::size_of::<sys::UserAchievementStored_t>() as i32;
 
     unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
         let val = &mut *(raw as *mut sys::UserAchievementStored_t);
-        let name = CStr::from_ptr(val.m_rgchAchievementName.as_ptr()).to_owned();
+        let name = CStr::from_ptr(val.m_rgchAchievementName.as_ptr());
         Self {
             game_id: GameId(val.m_nGameID),
-            achievement_name: name.into_string().unwrap(),
+            achievement_name: name.to_string_lossy().into_owned(),
             current_progress: val.m_nCurProgress,
             max_progress: val.m_nMaxProgress,
         }
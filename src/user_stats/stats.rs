@@ -78,3 +78,96 @@ impl<M> AchievementHelper<'_, M> {
         if success { Ok(()) } else { Err(()) }
     }
 }
+
+/// Stat API.
+///
+/// Methods require
+/// [`request_current_stats()`](../struct.UserStats.html#method.request_current_stats)
+/// to have been called and a successful [`UserStatsReceived`](../struct.UserStatsReceived.html)
+/// callback processed.
+///
+/// # Example
+///
+/// ```no_run
+/// # use steamworks::*;
+/// # let (client, single) = steamworks::Client::init().unwrap();
+/// let kills = client.user_stats().stat("KILLS");
+/// kills.set_i32(kills.get_i32()? + 1)?;
+/// # Err(())
+/// ```
+pub struct StatHelper<'parent, M> {
+    pub(crate) name: CString,
+    pub(crate) parent: &'parent UserStats<M>,
+}
+
+impl<M> StatHelper<'_, M> {
+    /// Gets the value of an integer stat.
+    ///
+    /// Fails if this stat's 'API Name' is unknown, the stat isn't an
+    /// integer, or unsuccessful [`UserStatsReceived`](../struct.UserStatsReceived.html).
+    pub fn get_i32(&self) -> Result<i32, ()> {
+        unsafe {
+            let mut value = 0;
+            let success = sys::SteamAPI_ISteamUserStats_GetStatInt32(
+                self.parent.user_stats,
+                self.name.as_ptr() as *const _,
+                &mut value,
+            );
+            if success { Ok(value) } else { Err(()) }
+        }
+    }
+
+    /// Sets the value of an integer stat.
+    ///
+    /// This call only modifies Steam's in-memory state so it is quite cheap. To send the
+    /// value to the server you must call
+    /// [`store_stats()`](../struct.UserStats.html#method.store_stats).
+    ///
+    /// Fails if this stat's 'API Name' is unknown, the stat isn't an
+    /// integer, or unsuccessful [`UserStatsReceived`](../struct.UserStatsReceived.html).
+    pub fn set_i32(&self, value: i32) -> Result<(), ()> {
+        let success = unsafe {
+            sys::SteamAPI_ISteamUserStats_SetStatInt32(
+                self.parent.user_stats,
+                self.name.as_ptr() as *const _,
+                value,
+            )
+        };
+        if success { Ok(()) } else { Err(()) }
+    }
+
+    /// Gets the value of a float stat.
+    ///
+    /// Fails if this stat's 'API Name' is unknown, the stat isn't a
+    /// float, or unsuccessful [`UserStatsReceived`](../struct.UserStatsReceived.html).
+    pub fn get_f32(&self) -> Result<f32, ()> {
+        unsafe {
+            let mut value = 0.0;
+            let success = sys::SteamAPI_ISteamUserStats_GetStatFloat(
+                self.parent.user_stats,
+                self.name.as_ptr() as *const _,
+                &mut value,
+            );
+            if success { Ok(value) } else { Err(()) }
+        }
+    }
+
+    /// Sets the value of a float stat.
+    ///
+    /// This call only modifies Steam's in-memory state so it is quite cheap. To send the
+    /// value to the server you must call
+    /// [`store_stats()`](../struct.UserStats.html#method.store_stats).
+    ///
+    /// Fails if this stat's 'API Name' is unknown, the stat isn't a
+    /// float, or unsuccessful [`UserStatsReceived`](../struct.UserStatsReceived.html).
+    pub fn set_f32(&self, value: f32) -> Result<(), ()> {
+        let success = unsafe {
+            sys::SteamAPI_ISteamUserStats_SetStatFloat(
+                self.parent.user_stats,
+                self.name.as_ptr() as *const _,
+                value,
+            )
+        };
+        if success { Ok(()) } else { Err(()) }
+    }
+}
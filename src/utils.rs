@@ -20,6 +20,26 @@ pub enum NotificationPosition {
     BottomRight,
 }
 
+/// The kind of text the gamepad text input accepts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GamepadTextInputMode {
+    /// The entered text is shown
+    Normal,
+    /// The entered text is hidden
+    Password,
+}
+
+/// Whether the gamepad text input accepts multiple lines
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GamepadTextInputLineMode {
+    /// Only a single line can be entered
+    SingleLine,
+    /// Multiple lines can be entered
+    MultipleLines,
+}
+
 lazy_static! {
     /// Global rust warning callback
     static ref WARNING_CALLBACK: RwLock<Option<Box<dyn Fn(i32, &CStr) + Send + Sync>>> = RwLock::new(None);
@@ -51,6 +71,19 @@ unsafe extern "C" fn c_warning_callback(level: i32, msg: *const c_char) {
 }
 
 impl <Manager> Utils<Manager> {
+    /// Returns the raw `ISteamUtils` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamUtils {
+        self.utils
+    }
+
     /// Returns the app ID of the current process
     pub fn app_id(&self) -> AppId {
         unsafe {
@@ -70,6 +103,52 @@ impl <Manager> Utils<Manager> {
         }
     }
 
+    /// Returns the number of IPC calls made to the steam client
+    /// since the last time this was called.
+    ///
+    /// Calling this resets the counter, so calling it once per
+    /// frame gives the number of calls made during that frame.
+    pub fn ipc_call_count(&self) -> u32 {
+        unsafe {
+            sys::SteamAPI_ISteamUtils_GetIPCCallCount(self.utils)
+        }
+    }
+
+    /// Returns whether the game was launched through the
+    /// Steam China launcher.
+    ///
+    /// Builds distributed in China can use this to enable the
+    /// content variations required there.
+    pub fn is_steam_china_launcher(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamUtils_IsSteamChinaLauncher(self.utils)
+        }
+    }
+
+    /// Returns whether the steam overlay is enabled and running
+    /// for this game.
+    ///
+    /// The overlay can fail to start, e.g. with some fullscreen
+    /// exclusive renderers, in which case its shortcuts such as the
+    /// screenshot key won't work either. Games can then handle the
+    /// key themselves and use `Screenshots::hook_screenshots`.
+    pub fn is_overlay_enabled(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamUtils_IsOverlayEnabled(self.utils)
+        }
+    }
+
+    /// Returns whether the overlay needs the game to present a
+    /// frame.
+    ///
+    /// Games that only redraw on changes should keep presenting
+    /// while this is true so the overlay stays up to date.
+    pub fn overlay_needs_present(&self) -> bool {
+        unsafe {
+            sys::SteamAPI_ISteamUtils_BOverlayNeedsPresent(self.utils)
+        }
+    }
+
     /// Sets the position on the screen where popups from the steam overlay
     /// should appear and display themselves in.
     pub fn set_overlay_notification_position(&self, position: NotificationPosition) {
@@ -84,6 +163,46 @@ impl <Manager> Utils<Manager> {
         }
     }
 
+    /// Opens the big picture gamepad text input.
+    ///
+    /// Returns whether the input was shown. Once the user closes it
+    /// `GamepadTextInputDismissed` is sent and the text can be read
+    /// with `entered_gamepad_text`.
+    pub fn show_gamepad_text_input(
+        &self, mode: GamepadTextInputMode, line_mode: GamepadTextInputLineMode,
+        description: &str, max_chars: u32, existing_text: &str,
+    ) -> bool {
+        unsafe {
+            let description = CString::new(description).unwrap();
+            let existing_text = CString::new(existing_text).unwrap();
+            let mode = match mode {
+                GamepadTextInputMode::Normal => sys::EGamepadTextInputMode::k_EGamepadTextInputModeNormal,
+                GamepadTextInputMode::Password => sys::EGamepadTextInputMode::k_EGamepadTextInputModePassword,
+            };
+            let line_mode = match line_mode {
+                GamepadTextInputLineMode::SingleLine => sys::EGamepadTextInputLineMode::k_EGamepadTextInputLineModeSingleLine,
+                GamepadTextInputLineMode::MultipleLines => sys::EGamepadTextInputLineMode::k_EGamepadTextInputLineModeMultipleLines,
+            };
+            sys::SteamAPI_ISteamUtils_ShowGamepadTextInput(
+                self.utils, mode, line_mode,
+                description.as_ptr(), max_chars, existing_text.as_ptr(),
+            )
+        }
+    }
+
+    /// Returns the text entered into the gamepad text input, if any
+    pub fn entered_gamepad_text(&self) -> Option<String> {
+        unsafe {
+            let len = sys::SteamAPI_ISteamUtils_GetEnteredGamepadTextLength(self.utils);
+            let mut buf = vec![0u8; len as usize + 1];
+            if !sys::SteamAPI_ISteamUtils_GetEnteredGamepadTextInput(self.utils, buf.as_mut_ptr() as *mut _, buf.len() as u32) {
+                return None;
+            }
+            let text = CStr::from_ptr(buf.as_ptr() as *const _);
+            Some(text.to_string_lossy().into_owned())
+        }
+    }
+
     /// Sets the Steam warning callback, which is called to emit warning messages.
     ///
     /// The passed-in function takes two arguments: a severity level (0 = info, 1 = warning) and
@@ -100,3 +219,25 @@ impl <Manager> Utils<Manager> {
         }
     }
 }
+
+/// Called when the big picture gamepad text input has been closed
+#[derive(Clone, Debug)]
+pub struct GamepadTextInputDismissed {
+    /// Whether the user submitted the text instead of cancelling
+    pub submitted: bool,
+    /// The length of the submitted text in bytes
+    pub submitted_text_len: u32,
+}
+
+unsafe impl Callback for GamepadTextInputDismissed {
+    const ID: i32 = 714;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GamepadTextInputDismissed_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GamepadTextInputDismissed_t);
+        GamepadTextInputDismissed {
+            submitted: val.m_bSubmitted,
+            submitted_text_len: val.m_unSubmittedText,
+        }
+    }
+}
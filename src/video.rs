@@ -0,0 +1,127 @@
+use super::*;
+
+const CALLBACK_BASE_ID: i32 = 4600;
+
+/// Access to the steam video interface
+pub struct Video<Manager> {
+    pub(crate) video: *mut sys::ISteamVideo,
+    pub(crate) _inner: Arc<Inner<Manager>>,
+}
+
+impl <Manager> Video<Manager> {
+    /// Returns the raw `ISteamVideo` interface pointer
+    ///
+    /// # Safety
+    ///
+    /// The pointer is only valid while a `Client` or `Server` handle
+    /// is alive, steam is shut down once the last one is dropped.
+    /// The handles are `Send` and `Sync` so it can be used from any
+    /// thread, but callbacks that raw calls trigger are only delivered
+    /// by `SingleClient::run_callbacks`. See `sys` for more.
+    pub unsafe fn as_raw(&self) -> *mut sys::ISteamVideo {
+        self.video
+    }
+
+    /// Requests the url of the video stream for the given app.
+    ///
+    /// The url is returned in a `GetVideoUrlResult` callback.
+    pub fn request_video_url(&self, app_id: AppId) {
+        unsafe {
+            sys::SteamAPI_ISteamVideo_GetVideoURL(self.video, app_id.0);
+        }
+    }
+
+    /// Returns whether the user is currently broadcasting and if so
+    /// the number of viewers.
+    pub fn is_broadcasting(&self) -> Option<u32> {
+        unsafe {
+            let mut viewers = 0;
+            if sys::SteamAPI_ISteamVideo_IsBroadcasting(self.video, &mut viewers) {
+                Some(viewers as u32)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Requests the OPF settings of the 360 video for the given app.
+    ///
+    /// A `GetOpfSettingsResult` callback is fired once the settings
+    /// are available to `opf_string_for_app`.
+    pub fn request_opf_settings(&self, app_id: AppId) {
+        unsafe {
+            sys::SteamAPI_ISteamVideo_GetOPFSettings(self.video, app_id.0);
+        }
+    }
+
+    /// Returns the OPF settings requested with `request_opf_settings`
+    ///
+    /// Returns `None` if the settings haven't been loaded yet.
+    pub fn opf_string_for_app(&self, app_id: AppId) -> Option<String> {
+        unsafe {
+            let mut len = 0;
+            if !sys::SteamAPI_ISteamVideo_GetOPFStringForApp(self.video, app_id.0, std::ptr::null_mut(), &mut len) {
+                return None;
+            }
+            let mut buffer = vec![0; len.max(1) as usize];
+            if !sys::SteamAPI_ISteamVideo_GetOPFStringForApp(self.video, app_id.0, buffer.as_mut_ptr(), &mut len) {
+                return None;
+            }
+            Some(CStr::from_ptr(buffer.as_ptr()).to_string_lossy().into_owned())
+        }
+    }
+}
+
+/// Called with the url requested by `Video::request_video_url`
+#[derive(Clone, Debug)]
+pub struct GetVideoUrlResult {
+    /// The app the video belongs to
+    pub app_id: AppId,
+    /// The url of the video stream if the request succeeded
+    pub url: SResult<String>,
+}
+
+unsafe impl Callback for GetVideoUrlResult {
+    const ID: i32 = CALLBACK_BASE_ID + 11;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GetVideoURLResult_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GetVideoURLResult_t);
+        GetVideoUrlResult {
+            app_id: AppId(val.m_unVideoAppID),
+            url: match val.m_eResult {
+                sys::EResult::k_EResultOK => {
+                    let url = CStr::from_ptr(val.m_rgchURL.as_ptr());
+                    Ok(url.to_string_lossy().into_owned())
+                },
+                err => Err(err.into()),
+            },
+        }
+    }
+}
+
+/// Called when the settings requested by `Video::request_opf_settings`
+/// have been loaded
+#[derive(Clone, Debug)]
+pub struct GetOpfSettingsResult {
+    /// The app the settings belong to
+    pub app_id: AppId,
+    /// Whether the settings were loaded successfully
+    pub result: SResult<()>,
+}
+
+unsafe impl Callback for GetOpfSettingsResult {
+    const ID: i32 = CALLBACK_BASE_ID + 24;
+    const SIZE: i32 = ::std::mem::size_of::<sys::GetOPFSettingsResult_t>() as i32;
+
+    unsafe fn from_raw(raw: *mut libc::c_void) -> Self {
+        let val = &mut *(raw as *mut sys::GetOPFSettingsResult_t);
+        GetOpfSettingsResult {
+            app_id: AppId(val.m_unVideoAppID),
+            result: match val.m_eResult {
+                sys::EResult::k_EResultOK => Ok(()),
+                err => Err(err.into()),
+            },
+        }
+    }
+}
@@ -0,0 +1,265 @@
+//! Voice chat between players over steam's p2p networking.
+//!
+//! `VoiceChat` records the local user's voice, sends it to every
+//! added peer and decompresses the voice received from peers into a
+//! playback buffer per peer. The buffers hold signed 16 bit mono
+//! samples at `VoiceChat::sample_rate` which can be handed to any
+//! audio output.
+//!
+//! ```no_run
+//! # use steamworks::*;
+//! # use steamworks::voice_chat::*;
+//! # let (client, single) = Client::init().unwrap();
+//! # let friend = SteamId::from_raw(0);
+//! let net_client = client.clone();
+//! let _session_request = client.register_callback(move |v: P2PSessionRequest| {
+//!     if v.remote == friend {
+//!         net_client.networking().accept_p2p_session(v.remote);
+//!     }
+//! });
+//! let mut voice = VoiceChat::new(&client);
+//! voice.add_peer(friend);
+//! voice.start_recording();
+//! let mut samples = [0i16; 1024];
+//! loop {
+//!     single.run_callbacks();
+//!     voice.update();
+//!     let count = voice.read_samples(friend, &mut samples);
+//!     // play samples[..count]
+//! #   break;
+//! }
+//! ```
+//!
+//! Only available with the `voice_chat` feature.
+
+use super::*;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Packets larger than this are sent reliably as steam won't
+/// send them unreliably
+const MAX_UNRELIABLE_SIZE: usize = 1200;
+
+/// The range of sample rates steam can decompress voice at
+const MIN_SAMPLE_RATE: u32 = 11025;
+const MAX_SAMPLE_RATE: u32 = 48000;
+
+fn clamp_sample_rate(sample_rate: u32) -> u32 {
+    sample_rate.max(MIN_SAMPLE_RATE).min(MAX_SAMPLE_RATE)
+}
+
+struct Peer {
+    samples: VecDeque<i16>,
+    last_voice: Option<Instant>,
+}
+
+/// Records, sends and plays back voice for a set of peers.
+///
+/// Voice is sent over `Networking`'s p2p sessions, which this doesn't
+/// accept by itself. The application must accept the sessions of its
+/// peers with `Networking::accept_p2p_session` when it receives a
+/// `P2PSessionRequest`, otherwise their voice is never received. A
+/// `LobbySession` already does this for the members of its lobby.
+///
+/// See the module documentation for an example.
+pub struct VoiceChat<Manager> {
+    user: User<Manager>,
+    networking: Networking<Manager>,
+    channel: i32,
+    sample_rate: u32,
+    max_buffered: usize,
+    speaking_timeout: Duration,
+
+    recording: bool,
+    last_local_voice: Option<Instant>,
+    peers: HashMap<SteamId, Peer>,
+
+    compressed: Vec<u8>,
+    decompressed: Vec<i16>,
+}
+
+impl <Manager> VoiceChat<Manager> {
+    /// Creates a voice chat without any peers.
+    ///
+    /// Voice is sent on p2p channel 1 so it doesn't mix with packets
+    /// on the default channel.
+    pub fn new(client: &Client<Manager>) -> VoiceChat<Manager> {
+        let user = client.user();
+        let sample_rate = clamp_sample_rate(user.voice_optimal_sample_rate());
+        VoiceChat {
+            user,
+            networking: client.networking(),
+            channel: 1,
+            sample_rate,
+            max_buffered: sample_rate as usize,
+            speaking_timeout: Duration::from_millis(250),
+
+            recording: false,
+            last_local_voice: None,
+            peers: HashMap::new(),
+
+            compressed: Vec::new(),
+            decompressed: Vec::new(),
+        }
+    }
+
+    /// Sets the p2p channel voice is sent and received on.
+    ///
+    /// Every peer must use the same channel.
+    pub fn channel(mut self, channel: i32) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Sets the sample rate of the playback buffers.
+    ///
+    /// Defaults to `User::voice_optimal_sample_rate`. Rates outside of
+    /// the 11025 to 48000 hz range steam supports are clamped to it.
+    pub fn sample_rate(mut self, sample_rate: u32) -> Self {
+        let sample_rate = clamp_sample_rate(sample_rate);
+        self.sample_rate = sample_rate;
+        self.max_buffered = sample_rate as usize;
+        self
+    }
+
+    /// Sets how long a peer counts as speaking after their last
+    /// voice packet. Defaults to 250 milliseconds.
+    pub fn speaking_timeout(mut self, timeout: Duration) -> Self {
+        self.speaking_timeout = timeout;
+        self
+    }
+
+    /// Returns the sample rate of the playback buffers
+    pub fn playback_sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Starts sending the local user's voice to the peers
+    pub fn start_recording(&mut self) {
+        self.user.start_voice_recording();
+        self.recording = true;
+    }
+
+    /// Stops sending the local user's voice.
+    ///
+    /// The remaining voice is still sent by the next few calls
+    /// to `update`.
+    pub fn stop_recording(&mut self) {
+        self.user.stop_voice_recording();
+        self.recording = false;
+    }
+
+    /// Returns whether the local user's voice is being recorded
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Adds a peer to send voice to and receive voice from
+    pub fn add_peer(&mut self, peer: SteamId) {
+        self.peers.entry(peer).or_insert_with(|| Peer {
+            samples: VecDeque::new(),
+            last_voice: None,
+        });
+    }
+
+    /// Removes a peer, dropping any voice buffered for them
+    pub fn remove_peer(&mut self, peer: SteamId) {
+        self.peers.remove(&peer);
+    }
+
+    /// Returns the current peers
+    pub fn peers(&self) -> Vec<SteamId> {
+        self.peers.keys().cloned().collect()
+    }
+
+    /// Sends recorded voice and receives voice from the peers.
+    ///
+    /// This should be called regularly, e.g. once per a frame.
+    pub fn update(&mut self) {
+        self.send_voice();
+        self.receive_voice();
+    }
+
+    fn send_voice(&mut self) {
+        loop {
+            match self.user.voice(&mut self.compressed) {
+                Ok(()) if !self.compressed.is_empty() => {},
+                _ => break,
+            }
+            self.last_local_voice = Some(Instant::now());
+            let send_type = if self.compressed.len() > MAX_UNRELIABLE_SIZE {
+                SendType::Reliable
+            } else {
+                SendType::UnreliableNoDelay
+            };
+            for &peer in self.peers.keys() {
+                self.networking.send_p2p_packet_on_channel(peer, send_type.clone(), &self.compressed, self.channel);
+            }
+        }
+    }
+
+    fn receive_voice(&mut self) {
+        while let Some(size) = self.networking.is_p2p_packet_available_on_channel(self.channel) {
+            let mut data = vec![0; size];
+            let (sender, len) = match self.networking.read_p2p_packet_from_channel(&mut data, self.channel) {
+                Some(v) => v,
+                None => break,
+            };
+            // Voice from players that aren't peers is ignored
+            let peer = match self.peers.get_mut(&sender) {
+                Some(peer) => peer,
+                None => continue,
+            };
+            if self.user.decompress_voice(&data[..len], self.sample_rate, &mut self.decompressed).is_err() {
+                continue;
+            }
+            peer.last_voice = Some(Instant::now());
+            peer.samples.extend(self.decompressed.iter().cloned());
+            // Drop the oldest samples if playback has fallen behind
+            let excess = peer.samples.len().saturating_sub(self.max_buffered);
+            peer.samples.drain(..excess);
+        }
+    }
+
+    /// Reads buffered samples of a peer's voice into `samples`.
+    ///
+    /// Returns the number of samples read, which is 0 if the peer
+    /// has nothing buffered.
+    pub fn read_samples(&mut self, peer: SteamId, samples: &mut [i16]) -> usize {
+        let peer = match self.peers.get_mut(&peer) {
+            Some(peer) => peer,
+            None => return 0,
+        };
+        let count = samples.len().min(peer.samples.len());
+        for (dst, src) in samples.iter_mut().zip(peer.samples.drain(..count)) {
+            *dst = src;
+        }
+        count
+    }
+
+    /// Returns the number of samples buffered for the peer
+    pub fn buffered_samples(&self, peer: SteamId) -> usize {
+        self.peers.get(&peer).map_or(0, |peer| peer.samples.len())
+    }
+
+    /// Returns whether voice was received from the peer recently
+    pub fn is_speaking(&self, peer: SteamId) -> bool {
+        self.peers.get(&peer)
+            .and_then(|peer| peer.last_voice)
+            .map_or(false, |last| last.elapsed() < self.speaking_timeout)
+    }
+
+    /// Returns whether the local user's voice was sent recently
+    pub fn is_local_speaking(&self) -> bool {
+        self.last_local_voice
+            .map_or(false, |last| last.elapsed() < self.speaking_timeout)
+    }
+}
+
+impl <Manager> Drop for VoiceChat<Manager> {
+    fn drop(&mut self) {
+        if self.recording {
+            self.user.stop_voice_recording();
+        }
+    }
+}
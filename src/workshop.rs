@@ -0,0 +1,383 @@
+//! High level helper for publishing workshop items.
+//!
+//! Publishing an item takes several steps: creating the item,
+//! starting an update, submitting it and then watching it upload.
+//! `Uploader` runs these as a single state machine that is driven
+//! by calling `Uploader::poll` after `SingleClient::run_callbacks`.
+//!
+//! ```no_run
+//! # use steamworks::*;
+//! # use steamworks::workshop::*;
+//! # use std::path::Path;
+//! # let (client, single) = Client::init().unwrap();
+//! let mut uploader = Uploader::new(client.ugc(), AppId(480))
+//!     .title("My map")
+//!     .content_path(Path::new("maps/my_map"));
+//! uploader.start();
+//! loop {
+//!     single.run_callbacks();
+//!     match uploader.poll() {
+//!         Some(UploadEvent::Finished { item, needs_legal_agreement }) => {
+//!             if needs_legal_agreement {
+//!                 client.friends().activate_game_overlay_to_web_page(&legal_agreement_url(item));
+//!             }
+//!             break;
+//!         },
+//!         Some(UploadEvent::Failed(err)) => panic!("{}", err),
+//!         _ => {},
+//!     }
+//! }
+//! ```
+//!
+//! If the upload fails after the item was created the same uploader
+//! can be restarted with `start`, which skips creating the item again.
+//! An item created by an earlier run can be updated with
+//! `Uploader::existing`.
+
+use super::*;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+type UploadResult = Arc<Mutex<Option<SResult<(PublishedFileId, bool)>>>>;
+
+/// Events produced by `Uploader::poll`
+#[derive(Clone, Debug, PartialEq)]
+pub enum UploadEvent {
+    /// The workshop item was created
+    Created(PublishedFileId),
+    /// The upload made progress
+    Progress {
+        status: UpdateStatus,
+        processed: u64,
+        total: u64,
+    },
+    /// A step failed with an error that may be temporary and will be
+    /// tried again after a delay
+    Retrying {
+        error: SteamError,
+        attempt: u32,
+    },
+    /// The item was uploaded
+    Finished {
+        item: PublishedFileId,
+        /// Whether the user must accept the workshop legal agreement
+        /// before the item becomes visible. See `legal_agreement_url`.
+        needs_legal_agreement: bool,
+    },
+    /// The upload failed
+    Failed(SteamError),
+}
+
+enum Step<Manager> {
+    Idle,
+    Creating(UploadResult),
+    Submitting(UpdateWatchHandle<Manager>, UploadResult),
+    /// Waiting before retrying the failed step
+    Waiting { retry_at: Instant },
+    /// A step couldn't be started, reported by the next `poll`
+    Rejected(SteamError),
+    Done,
+}
+
+/// Creates or updates a workshop item in one go.
+///
+/// See the module documentation for an example.
+pub struct Uploader<Manager> {
+    ugc: UGC<Manager>,
+    app_id: AppId,
+    file_type: FileType,
+    item: Option<PublishedFileId>,
+    needs_legal_agreement: bool,
+
+    title: Option<String>,
+    description: Option<String>,
+    content_path: Option<PathBuf>,
+    preview_path: Option<PathBuf>,
+    tags: Option<Vec<String>>,
+    visibility: Option<PublishedFileVisibility>,
+    change_note: Option<String>,
+
+    max_retries: u32,
+    retry_delay: Duration,
+    attempt: u32,
+    last_progress: Option<(UpdateStatus, u64, u64)>,
+    step: Step<Manager>,
+}
+
+impl <Manager> Uploader<Manager> {
+    /// Creates an uploader that publishes a new community item
+    pub fn new(ugc: UGC<Manager>, app_id: AppId) -> Uploader<Manager> {
+        Uploader {
+            ugc,
+            app_id,
+            file_type: FileType::Community,
+            item: None,
+            needs_legal_agreement: false,
+
+            title: None,
+            description: None,
+            content_path: None,
+            preview_path: None,
+            tags: None,
+            visibility: None,
+            change_note: None,
+
+            max_retries: 3,
+            retry_delay: Duration::from_secs(1),
+            attempt: 0,
+            last_progress: None,
+            step: Step::Idle,
+        }
+    }
+
+    /// Creates an uploader that updates an existing item
+    pub fn existing(ugc: UGC<Manager>, app_id: AppId, item: PublishedFileId) -> Uploader<Manager> {
+        let mut uploader = Uploader::new(ugc, app_id);
+        uploader.item = Some(item);
+        uploader
+    }
+
+    /// Sets the type of item created. Defaults to `FileType::Community`
+    pub fn file_type(mut self, file_type: FileType) -> Self {
+        self.file_type = file_type;
+        self
+    }
+
+    /// Sets the title of the item
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    /// Sets the description of the item
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    /// Sets the folder containing the item's content
+    pub fn content_path(mut self, path: &Path) -> Self {
+        self.content_path = Some(path.to_owned());
+        self
+    }
+
+    /// Sets the main preview image of the item
+    pub fn preview_path(mut self, path: &Path) -> Self {
+        self.preview_path = Some(path.to_owned());
+        self
+    }
+
+    /// Replaces the tags of the item
+    pub fn tags<S: AsRef<str>>(mut self, tags: &[S]) -> Self {
+        self.tags = Some(tags.iter().map(|v| v.as_ref().to_owned()).collect());
+        self
+    }
+
+    /// Sets who can see the item
+    pub fn visibility(mut self, visibility: PublishedFileVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    /// Sets the change note shown in the item's history
+    pub fn change_note(mut self, note: &str) -> Self {
+        self.change_note = Some(note.to_owned());
+        self
+    }
+
+    /// Sets how many times a step that failed with a temporary
+    /// error is retried. Defaults to 3.
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Sets how long to wait before the first retry of a failed step.
+    /// The wait doubles with each following retry. Defaults to 1 second.
+    pub fn retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Returns the id of the item once it has been created
+    pub fn item(&self) -> Option<PublishedFileId> {
+        self.item
+    }
+
+    /// Returns whether an upload is currently running
+    pub fn is_running(&self) -> bool {
+        matches!(self.step, Step::Creating(_) | Step::Submitting(..) | Step::Waiting { .. })
+    }
+
+    /// Starts the upload, or restarts it after it failed.
+    ///
+    /// Does nothing if an upload is already running.
+    pub fn start(&mut self) {
+        if self.is_running() {
+            return;
+        }
+        self.attempt = 0;
+        self.run_step();
+    }
+
+    /// Advances the upload, returning the next event if there is one.
+    ///
+    /// This should be called regularly after running callbacks.
+    pub fn poll(&mut self) -> Option<UploadEvent> {
+        let result = match &self.step {
+            Step::Idle | Step::Done => return None,
+            &Step::Rejected(err) => {
+                self.step = Step::Idle;
+                return Some(UploadEvent::Failed(err));
+            },
+            &Step::Waiting { retry_at } => {
+                if Instant::now() >= retry_at {
+                    self.run_step();
+                }
+                return None;
+            },
+            Step::Creating(result) => result.lock().unwrap().take(),
+            Step::Submitting(watch, result) => {
+                let result = result.lock().unwrap().take();
+                if result.is_none() {
+                    let progress = watch.progress();
+                    if self.last_progress == Some(progress) {
+                        return None;
+                    }
+                    self.last_progress = Some(progress);
+                    return Some(UploadEvent::Progress {
+                        status: progress.0,
+                        processed: progress.1,
+                        total: progress.2,
+                    });
+                }
+                result
+            },
+        };
+        let (item, needs_legal_agreement) = match result? {
+            Ok(v) => v,
+            Err(err) => return Some(self.fail(err)),
+        };
+        self.needs_legal_agreement |= needs_legal_agreement;
+        self.attempt = 0;
+        match self.step {
+            Step::Creating(_) => {
+                self.item = Some(item);
+                self.run_step();
+                Some(UploadEvent::Created(item))
+            },
+            _ => {
+                self.step = Step::Done;
+                Some(UploadEvent::Finished {
+                    item,
+                    needs_legal_agreement: self.needs_legal_agreement,
+                })
+            },
+        }
+    }
+
+    fn fail(&mut self, error: SteamError) -> UploadEvent {
+        if is_temporary(&error) && self.attempt < self.max_retries {
+            self.attempt += 1;
+            let delay = self.retry_delay * 2u32.pow((self.attempt - 1).min(16));
+            self.step = Step::Waiting { retry_at: Instant::now() + delay };
+            UploadEvent::Retrying {
+                error,
+                attempt: self.attempt,
+            }
+        } else {
+            self.step = Step::Idle;
+            UploadEvent::Failed(error)
+        }
+    }
+
+    fn run_step(&mut self) {
+        // Checked before anything is sent to steam as the update
+        // panics on paths that can't be resolved
+        let paths = canonicalize(self.content_path.as_deref())
+            .and_then(|content| Ok((content, canonicalize(self.preview_path.as_deref())?)));
+        let (content_path, preview_path) = match paths {
+            Ok(v) => v,
+            Err(err) => {
+                self.step = Step::Rejected(err);
+                return;
+            },
+        };
+
+        let result: UploadResult = Arc::new(Mutex::new(None));
+        let slot = Arc::clone(&result);
+        let cb = move |res| *slot.lock().unwrap() = Some(res);
+
+        let item = match self.item {
+            Some(item) => item,
+            None => {
+                self.ugc.create_item(self.app_id, self.file_type, cb);
+                self.step = Step::Creating(result);
+                return;
+            },
+        };
+
+        let mut update = self.ugc.start_item_update(self.app_id, item);
+        if let Some(title) = &self.title {
+            update = update.title(title);
+        }
+        if let Some(description) = &self.description {
+            update = update.description(description);
+        }
+        if let Some(path) = &content_path {
+            update = update.content_path(path);
+        }
+        if let Some(path) = &preview_path {
+            update = update.preview_path(path);
+        }
+        if let Some(tags) = &self.tags {
+            update = update.tags(&tags[..]);
+        }
+        if let Some(visibility) = self.visibility {
+            update = update.visibility(visibility);
+        }
+        let watch = update.submit(self.change_note.as_deref(), cb);
+        self.last_progress = None;
+        self.step = Step::Submitting(watch, result);
+    }
+}
+
+fn canonicalize(path: Option<&Path>) -> SResult<Option<PathBuf>> {
+    path.map(|path| path.canonicalize().map_err(|err| match err.kind() {
+        std::io::ErrorKind::NotFound => SteamError::FileNotFound,
+        std::io::ErrorKind::PermissionDenied => SteamError::AccessDenied,
+        _ => SteamError::InvalidParameter,
+    })).transpose()
+}
+
+/// Returns whether retrying after the error may succeed
+fn is_temporary(error: &SteamError) -> bool {
+    matches!(error,
+        SteamError::IOFailure
+        | SteamError::Timeout
+        | SteamError::Busy
+        | SteamError::NoConnection
+        | SteamError::ServiceUnavailable
+        | SteamError::TooManyPending
+        | SteamError::RateLimitExceeded)
+}
+
+/// Returns the url of the item's page where the user can accept
+/// the workshop legal agreement.
+///
+/// This can be opened with `Friends::activate_game_overlay_to_web_page`.
+pub fn legal_agreement_url(item: PublishedFileId) -> String {
+    format!("steam://url/CommunityFilePage/{}", item.0)
+}
+
+#[test]
+fn temporary_errors() {
+    assert!(is_temporary(&SteamError::Timeout));
+    assert!(is_temporary(&SteamError::IOFailure));
+    assert!(!is_temporary(&SteamError::AccessDenied));
+    assert!(!is_temporary(&SteamError::LimitExceeded));
+    assert_eq!(
+        legal_agreement_url(PublishedFileId(1234)),
+        "steam://url/CommunityFilePage/1234"
+    );
+}
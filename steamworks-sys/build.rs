@@ -14,6 +14,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .expect("STEAM_SDK_LOCATION must be set");
     let sdk_loc = Path::new(&sdk_loc);
 
+    println!("cargo:rerun-if-env-changed=STEAM_SDK_LOCATION");
+
     let triple = env::var("TARGET").unwrap();
     let mut lib = "steam_api";
     let mut link_path = sdk_loc.join("redistributable_bin");
@@ -42,6 +44,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .header(sdk_loc.join("public/steam/steam_gameserver.h").to_string_lossy())
         .clang_arg("-xc++")
         .opaque_type("SteamTVRegion_t")
+        // Used as a set of flags so combinations must be representable
+        .newtype_enum("ISteamHTMLSurface_EHTMLKeyModifiers")
         .clang_arg(format!("-I{}", sdk_loc.join("public").display()))
         .rustfmt_bindings(true)
         .default_enum_style(bindgen::EnumVariation::Rust {
@@ -60,14 +64,31 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
         .expect("Couldn't write bindings!");
 
-    if triple.contains("windows") {
-        let file_name = format!("{}.dll", lib);
-        fs::copy(link_path.join(&file_name), out_path.join(file_name))?;
+    let file_name = if triple.contains("windows") {
+        format!("{}.dll", lib)
     } else if triple.contains("darwin") {
-        fs::copy(link_path.join("libsteam_api.dylib"), out_path.join("libsteam_api.dylib"))?;
-    } else if triple.contains("linux") {
-        fs::copy(link_path.join("libsteam_api.so"), out_path.join("libsteam_api.so"))?;
+        "libsteam_api.dylib".to_owned()
+    } else {
+        "libsteam_api.so".to_owned()
+    };
+    fs::copy(link_path.join(&file_name), out_path.join(&file_name))?;
+
+    // Optionally place the redistributable next to the final binary
+    // so that it can be found at startup. OUT_DIR is
+    // `<profile>/build/<pkg>/out`.
+    if env::var("STEAM_SDK_COPY").map_or(false, |v| v == "1") {
+        if let Some(profile_dir) = out_path.ancestors().nth(3) {
+            // A running game keeps the library locked on windows,
+            // which shouldn't fail the build
+            if let Err(err) = fs::copy(link_path.join(&file_name), profile_dir.join(&file_name)) {
+                println!("cargo:warning=Couldn't copy {} to {}: {}", file_name, profile_dir.display(), err);
+            }
+        }
     }
+    println!("cargo:rerun-if-env-changed=STEAM_SDK_COPY");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/fixes.hpp");
+    println!("cargo:rerun-if-changed={}", sdk_loc.join("public/steam").display());
 
     Ok(())
 }
@@ -17032,15 +17032,25 @@ pub enum ISteamHTMLSurface_EMouseCursor {
     dc_custom = 40,
     dc_last = 41,
 }
-#[repr(u32)]
-#[non_exhaustive]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub enum ISteamHTMLSurface_EHTMLKeyModifiers {
-    k_eHTMLKeyModifier_None = 0,
-    k_eHTMLKeyModifier_AltDown = 1,
-    k_eHTMLKeyModifier_CtrlDown = 2,
-    k_eHTMLKeyModifier_ShiftDown = 4,
+impl ISteamHTMLSurface_EHTMLKeyModifiers {
+    pub const k_eHTMLKeyModifier_None: ISteamHTMLSurface_EHTMLKeyModifiers =
+        ISteamHTMLSurface_EHTMLKeyModifiers(0);
+}
+impl ISteamHTMLSurface_EHTMLKeyModifiers {
+    pub const k_eHTMLKeyModifier_AltDown: ISteamHTMLSurface_EHTMLKeyModifiers =
+        ISteamHTMLSurface_EHTMLKeyModifiers(1);
 }
+impl ISteamHTMLSurface_EHTMLKeyModifiers {
+    pub const k_eHTMLKeyModifier_CtrlDown: ISteamHTMLSurface_EHTMLKeyModifiers =
+        ISteamHTMLSurface_EHTMLKeyModifiers(2);
+}
+impl ISteamHTMLSurface_EHTMLKeyModifiers {
+    pub const k_eHTMLKeyModifier_ShiftDown: ISteamHTMLSurface_EHTMLKeyModifiers =
+        ISteamHTMLSurface_EHTMLKeyModifiers(4);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ISteamHTMLSurface_EHTMLKeyModifiers(pub u32);
 #[test]
 fn bindgen_test_layout_ISteamHTMLSurface() {
     assert_eq!(
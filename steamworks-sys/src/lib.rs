@@ -4,4 +4,9 @@
 
 extern crate libc;
 
-include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
\ No newline at end of file
+// The build script doesn't run bindgen for docs builds, so the
+// committed bindings are used instead
+#[cfg(feature = "docs-only")]
+include!("bindings.rs");
+#[cfg(not(feature = "docs-only"))]
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));